@@ -0,0 +1,26 @@
+//! Expose the metrics of the client in the Prometheus text format.
+//!
+//! Run with the credentials in `ZOHO_CLIENT_ID`, `ZOHO_CLIENT_SECRET` and `ZOHO_REFRESH_TOKEN`:
+//!
+//! ```sh
+//! cargo run --example prometheus --features metrics
+//! ```
+
+use metrics_exporter_prometheus::PrometheusBuilder;
+use zohoxide_crm::Client;
+
+fn main() {
+    let handle = PrometheusBuilder::new()
+        .install_recorder()
+        .expect("failed to install the Prometheus recorder");
+
+    let mut client = Client::from_env().expect("missing Zoho credentials");
+
+    match client.get_many::<serde_json::Value>("Accounts", None) {
+        Ok(response) => println!("Fetched {} accounts", response.data.len()),
+        Err(error) => eprintln!("Failed to fetch accounts: {}", error),
+    }
+
+    // a real service would serve this from its `/metrics` endpoint
+    println!("{}", handle.render());
+}
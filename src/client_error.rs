@@ -1,12 +1,19 @@
 use crate::response::ApiErrorResponse;
+use std::any::Any;
+use std::fmt;
 use thiserror::Error;
 
 /// Various errors returned by the API.
 #[derive(Error, Debug)]
 pub enum ClientError {
-    /// General error message that encompasses almost any non-token related error message.
-    #[error("{0}")]
-    General(String),
+    /// General error that encompasses almost any non-token related error.
+    ///
+    /// The original error (such as a `reqwest::Error`) is kept in the variant, so it can be
+    /// downcast. Its message and source are forwarded as is, so the error chain does not repeat
+    /// it. Errors raised by this crate itself are wrapped in an
+    /// [`ErrorMessage`](struct.ErrorMessage.html).
+    #[error(transparent)]
+    General(Box<dyn std::error::Error + Send + Sync>),
 
     /// Error returned when a response from the API does not deserialize into the user's
     /// custom data type. The raw response will be returned with this error.
@@ -17,31 +24,141 @@ pub enum ClientError {
     #[error("Empty response")]
     EmptyResponse,
 
-    /// Error returned from most API requests.
+    /// Error returned when an expected record is missing from a successful response.
+    #[error("{0}")]
+    NotFound(String),
+
+    /// Error returned from most API requests, displayed as `[CODE] message`.
     #[error("{0}")]
     ApiError(ApiErrorResponse),
+
+    /// Error returned when a background job, such as a bulk read, ends in a failed state.
+    #[error("Job {job_id} failed with state {state}")]
+    JobFailed { job_id: String, state: String },
+
+    /// Error returned when a background job is still not done once the allowed time is over.
+    #[error("Timed out waiting for job {job_id}, last in state {state}")]
+    JobTimeout { job_id: String, state: String },
+
+    /// Error returned when a write split into several batches fails part way through.
+    /// `completed_batches` batches were written before `source` occurred, and the write can
+    /// be resumed from the record at `first_unsent_index` in the input.
+    #[error("Batch failed after {completed_batches} completed batches: {source}")]
+    BatchFailed {
+        completed_batches: usize,
+        first_unsent_index: usize,
+        source: Box<ClientError>,
+    },
+
+    /// Error returned when Zoho acknowledged a write but appending it to the journal failed.
+    ///
+    /// The write must not be retried. `response` holds what the method would have returned,
+    /// such as a `response::ApiSuccessResponse`, and can be downcast to that type. When the
+    /// write was split into several batches, this error is the source of a `BatchFailed` error
+    /// and `response` holds the results of every batch sent so far.
+    #[error("Write succeeded but could not be journaled: {source}")]
+    JournalFailed {
+        response: Box<dyn Any + Send + Sync>,
+        source: Box<ClientError>,
+    },
+}
+
+impl From<ContextError> for ClientError {
+    fn from(err: ContextError) -> Self {
+        ClientError::General(Box::new(err))
+    }
 }
 
 impl From<serde_json::Error> for ClientError {
     fn from(err: serde_json::Error) -> Self {
-        ClientError::General(err.to_string())
+        ClientError::General(Box::new(err))
     }
 }
 
 impl From<serde_urlencoded::ser::Error> for ClientError {
     fn from(err: serde_urlencoded::ser::Error) -> Self {
-        ClientError::General(err.to_string())
+        ClientError::General(Box::new(err))
+    }
+}
+
+impl From<std::io::Error> for ClientError {
+    fn from(err: std::io::Error) -> Self {
+        ClientError::General(Box::new(err))
     }
 }
 
 impl From<&str> for ClientError {
     fn from(err: &str) -> ClientError {
-        ClientError::General(String::from(err))
+        ClientError::from(String::from(err))
+    }
+}
+
+impl From<String> for ClientError {
+    fn from(err: String) -> ClientError {
+        ClientError::General(Box::new(ErrorMessage(err)))
     }
 }
 
 impl From<reqwest::Error> for ClientError {
     fn from(err: reqwest::Error) -> ClientError {
-        ClientError::General(err.to_string())
+        ClientError::General(Box::new(err))
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for ClientError {
+    fn from(err: csv::Error) -> ClientError {
+        ClientError::General(Box::new(err))
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<zip::result::ZipError> for ClientError {
+    fn from(err: zip::result::ZipError) -> ClientError {
+        ClientError::General(Box::new(err))
+    }
+}
+
+/// Error message raised by this crate rather than by one of its dependencies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorMessage(pub String);
+
+impl fmt::Display for ErrorMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ErrorMessage {}
+
+/// Error message raised by this crate about an error of one of its dependencies, kept as the
+/// source.
+#[derive(Debug)]
+pub(crate) struct ContextError {
+    message: String,
+    source: Box<dyn std::error::Error + Send + Sync>,
+}
+
+impl ContextError {
+    pub(crate) fn new(
+        message: String,
+        source: impl std::error::Error + Send + Sync + 'static,
+    ) -> ContextError {
+        ContextError {
+            message,
+            source: Box::new(source),
+        }
+    }
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ContextError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.source)
     }
 }
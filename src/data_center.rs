@@ -0,0 +1,63 @@
+/// A Zoho data center, each with its own OAuth and API domains.
+///
+/// Pass it to [`ClientBuilder::data_center`](struct.ClientBuilder.html#method.data_center) to
+/// set both domains at once. Use the `oauth_domain` and `api_domain` setters instead for custom
+/// deployments.
+///
+/// You can read more information here:
+/// [https://www.zoho.com/crm/developer/docs/api/v2/multi-dc.html](https://www.zoho.com/crm/developer/docs/api/v2/multi-dc.html)
+///
+/// ### Example
+///
+/// ```
+/// use zohoxide_crm::DataCenter;
+///
+/// assert_eq!(DataCenter::Eu.oauth_domain(), "https://accounts.zoho.eu");
+/// assert_eq!(DataCenter::Eu.api_domain(), "https://www.zohoapis.eu");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DataCenter {
+    /// United States, the default data center.
+    #[default]
+    Us,
+    /// Europe.
+    Eu,
+    /// Australia.
+    Au,
+    /// India.
+    In,
+    /// Japan.
+    Jp,
+    /// Canada.
+    Ca,
+    /// China.
+    Cn,
+}
+
+impl DataCenter {
+    /// Domain of the OAuth server, used to fetch access tokens.
+    pub fn oauth_domain(&self) -> &str {
+        match self {
+            DataCenter::Us => "https://accounts.zoho.com",
+            DataCenter::Eu => "https://accounts.zoho.eu",
+            DataCenter::Au => "https://accounts.zoho.com.au",
+            DataCenter::In => "https://accounts.zoho.in",
+            DataCenter::Jp => "https://accounts.zoho.jp",
+            DataCenter::Ca => "https://accounts.zohocloud.ca",
+            DataCenter::Cn => "https://accounts.zoho.com.cn",
+        }
+    }
+
+    /// Domain of the CRM API.
+    pub fn api_domain(&self) -> &str {
+        match self {
+            DataCenter::Us => "https://www.zohoapis.com",
+            DataCenter::Eu => "https://www.zohoapis.eu",
+            DataCenter::Au => "https://www.zohoapis.com.au",
+            DataCenter::In => "https://www.zohoapis.in",
+            DataCenter::Jp => "https://www.zohoapis.jp",
+            DataCenter::Ca => "https://www.zohoapis.ca",
+            DataCenter::Cn => "https://www.zohoapis.com.cn",
+        }
+    }
+}
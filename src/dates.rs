@@ -0,0 +1,151 @@
+//! Dates and times in the formats Zoho uses, with `serde` support.
+//!
+//! This module requires the `chrono` feature.
+//!
+//! ### Example
+//!
+//! ```
+//! use serde::Deserialize;
+//! use zohoxide_crm::dates::{ZohoDate, ZohoDateTime};
+//!
+//! #[derive(Deserialize)]
+//! struct Deal {
+//!     #[serde(rename = "Closing_Date")]
+//!     closing_date: ZohoDate,
+//!     #[serde(rename = "Modified_Time")]
+//!     modified_time: ZohoDateTime,
+//! }
+//!
+//! let deal: Deal = serde_json::from_str(
+//!     r#"{"Closing_Date":"2023-06-30","Modified_Time":"2023-05-02T11:00:00+02:00"}"#,
+//! )
+//! .unwrap();
+//!
+//! let closing_date: chrono::NaiveDate = deal.closing_date.into();
+//! assert_eq!(closing_date.to_string(), "2023-06-30");
+//! assert_eq!(deal.modified_time.to_string(), "2023-05-02T11:00:00+02:00");
+//! ```
+
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Format of the date fields, such as `2023-06-30`.
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Format of the date and time fields, such as `2023-05-02T11:00:00+02:00`.
+const DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%:z";
+
+/// Value of a date field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZohoDate(pub NaiveDate);
+
+/// Value of a date and time field, with the UTC offset Zoho sent it with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZohoDateTime(pub DateTime<FixedOffset>);
+
+impl From<NaiveDate> for ZohoDate {
+    fn from(date: NaiveDate) -> ZohoDate {
+        ZohoDate(date)
+    }
+}
+
+impl From<ZohoDate> for NaiveDate {
+    fn from(date: ZohoDate) -> NaiveDate {
+        date.0
+    }
+}
+
+impl From<DateTime<FixedOffset>> for ZohoDateTime {
+    fn from(date_time: DateTime<FixedOffset>) -> ZohoDateTime {
+        ZohoDateTime(date_time)
+    }
+}
+
+impl From<ZohoDateTime> for DateTime<FixedOffset> {
+    fn from(date_time: ZohoDateTime) -> DateTime<FixedOffset> {
+        date_time.0
+    }
+}
+
+impl fmt::Display for ZohoDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.format(DATE_FORMAT))
+    }
+}
+
+impl fmt::Display for ZohoDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.format(DATE_TIME_FORMAT))
+    }
+}
+
+impl Serialize for ZohoDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for ZohoDateTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ZohoDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        NaiveDate::parse_from_str(&value, DATE_FORMAT)
+            .map(ZohoDate)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl FromStr for ZohoDateTime {
+    type Err = chrono::ParseError;
+
+    /// Parse a date and time in ISO 8601 format, with a UTC offset or `Z`. The seconds may have a
+    /// fraction.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        DateTime::parse_from_rfc3339(value).map(ZohoDateTime)
+    }
+}
+
+impl<'de> Deserialize<'de> for ZohoDateTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// Tests that a date is read and written back as `YYYY-MM-DD`, and an impossible date is rejected.
+    fn date_round_trip() {
+        let date: ZohoDate = serde_json::from_str(r#""2024-02-29""#).unwrap();
+
+        assert_eq!(date.0, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+        assert_eq!(serde_json::to_string(&date).unwrap(), r#""2024-02-29""#);
+        assert!(serde_json::from_str::<ZohoDate>(r#""2023-02-29""#).is_err());
+    }
+
+    #[test]
+    /// Tests that a date and time keeps the UTC offset Zoho sent it with, and a bare date is rejected.
+    fn date_time_keeps_offset() {
+        let date_time: ZohoDateTime =
+            serde_json::from_str(r#""2023-05-02T04:00:00-05:00""#).unwrap();
+
+        assert_eq!(date_time.0.timestamp(), 1683018000);
+        assert_eq!(
+            serde_json::to_string(&date_time).unwrap(),
+            r#""2023-05-02T04:00:00-05:00""#
+        );
+        assert!(serde_json::from_str::<ZohoDateTime>(r#""2023-05-02""#).is_err());
+    }
+}
@@ -0,0 +1,281 @@
+//! Opt-in journal of the write operations made by a [`Client`](../struct.Client.html).
+//!
+//! Every successful request that modifies data in Zoho appends one [`JournalEntry`] to the
+//! configured [`JournalSink`]. Calls that are split into several requests (such as chunked
+//! inserts) produce one entry per request, so that a failure part way through never loses the
+//! writes Zoho already acknowledged. Reads are never journaled, and the few write methods that
+//! are not are listed on [`JournalSink`].
+//!
+//! ### Example
+//!
+//! ```no_run
+//! use zohoxide_crm::journal::FileJournal;
+//! use zohoxide_crm::Client;
+//!
+//! let journal = FileJournal::open("zoho-writes.jsonl").unwrap();
+//!
+//! let client = Client::builder()
+//!     .client_id("YOUR_CLIENT_ID")
+//!     .client_secret("YOUR_CLIENT_SECRET")
+//!     .refresh_token("YOUR_REFRESH_TOKEN")
+//!     .journal(journal)
+//!     .integration_name(Some(String::from("erp-sync")))
+//!     .build();
+//! ```
+
+use crate::client_error::ClientError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Kind of write operation recorded in the journal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalOperation {
+    Insert,
+    Update,
+    Upsert,
+    Delete,
+    Tag,
+    /// Update whose only field is `Owner`.
+    OwnerChange,
+    Convert,
+    Link,
+    Restore,
+    Merge,
+    Approval,
+    /// Change to the users a record is shared with.
+    Share,
+    /// Email sent on behalf of a record.
+    Email,
+}
+
+/// A single journaled write operation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Seconds since the Unix epoch at which Zoho acknowledged the write.
+    pub timestamp: u64,
+    pub operation: JournalOperation,
+    pub module: String,
+    /// IDs of the records Zoho reported as successfully written.
+    pub record_ids: Vec<String>,
+    /// API names of the fields sent with the request, when known.
+    pub fields: Vec<String>,
+    /// Name of the integration that made the write, as set on the client.
+    pub integration: Option<String>,
+}
+
+impl JournalEntry {
+    /// Create an entry timestamped with the current time.
+    pub fn new(
+        operation: JournalOperation,
+        module: &str,
+        record_ids: Vec<String>,
+        fields: Vec<String>,
+        integration: Option<String>,
+    ) -> JournalEntry {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        JournalEntry {
+            timestamp,
+            operation,
+            module: String::from(module),
+            record_ids,
+            fields,
+            integration,
+        }
+    }
+}
+
+/// Destination for journal entries.
+///
+/// `append()` is called synchronously before the write method returns, so implementations
+/// should only return once the entry is safely stored. When it fails, the write already
+/// succeeded in Zoho, so the method returns a
+/// [`ClientError::JournalFailed`](../enum.ClientError.html#variant.JournalFailed) error holding
+/// its response.
+///
+/// A few write methods are not journaled:
+///
+/// - `bulk_write_create()`, as the written records are only known once the job completes.
+/// - `composite()`, as its sub-requests are arbitrary paths the client does not interpret.
+/// - Webhook channels and uploaded files, which are not records.
+pub trait JournalSink: Send {
+    /// Store a new entry.
+    fn append(&mut self, entry: &JournalEntry) -> Result<(), ClientError>;
+
+    /// Entries recorded so far, for sinks that keep them in memory.
+    fn entries(&self) -> Option<Vec<JournalEntry>> {
+        None
+    }
+}
+
+/// Journal sink keeping every entry in memory.
+#[derive(Debug, Default)]
+pub struct MemoryJournal {
+    entries: Vec<JournalEntry>,
+}
+
+impl MemoryJournal {
+    pub fn new() -> MemoryJournal {
+        MemoryJournal::default()
+    }
+}
+
+impl JournalSink for MemoryJournal {
+    fn append(&mut self, entry: &JournalEntry) -> Result<(), ClientError> {
+        self.entries.push(entry.clone());
+        Ok(())
+    }
+
+    fn entries(&self) -> Option<Vec<JournalEntry>> {
+        Some(self.entries.clone())
+    }
+}
+
+/// Journal sink appending entries to a file, one JSON object per line.
+///
+/// Each entry is flushed and synced to disk before `append()` returns.
+#[derive(Debug)]
+pub struct FileJournal {
+    file: File,
+}
+
+impl FileJournal {
+    /// Open `path` for appending, creating it if it does not exist.
+    pub fn open(path: impl AsRef<Path>) -> Result<FileJournal, ClientError> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        Ok(FileJournal { file })
+    }
+}
+
+impl JournalSink for FileJournal {
+    fn append(&mut self, entry: &JournalEntry) -> Result<(), ClientError> {
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+
+        self.file.write_all(line.as_bytes())?;
+        self.file.flush()?;
+        self.file.sync_data()?;
+
+        Ok(())
+    }
+}
+
+/// Shared handle to the sink configured on a client. Cloning it shares the same sink.
+#[derive(Clone)]
+pub struct Journal {
+    sink: Arc<Mutex<dyn JournalSink>>,
+}
+
+impl Journal {
+    pub(crate) fn new(sink: impl JournalSink + 'static) -> Journal {
+        Journal {
+            sink: Arc::new(Mutex::new(sink)),
+        }
+    }
+
+    pub(crate) fn append(&self, entry: &JournalEntry) -> Result<(), ClientError> {
+        self.sink
+            .lock()
+            .map_err(|_| ClientError::from("Journal lock was poisoned"))?
+            .append(entry)
+    }
+
+    pub(crate) fn entries(&self) -> Option<Vec<JournalEntry>> {
+        self.sink.lock().ok()?.entries()
+    }
+}
+
+#[cfg(test)]
+impl PartialEq for Journal {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.sink, &other.sink)
+    }
+}
+
+#[cfg(test)]
+impl Eq for Journal {}
+
+/// Operation to journal for an update of `fields`, telling owner changes apart.
+pub(crate) fn update_operation(fields: &[String]) -> JournalOperation {
+    match fields {
+        [field] if field == "Owner" => JournalOperation::OwnerChange,
+        _ => JournalOperation::Update,
+    }
+}
+
+/// Collect the top-level field names of serialized records, for the journal.
+pub(crate) fn touched_fields<T: Serialize>(data: &[T]) -> Vec<String> {
+    let mut fields = BTreeSet::new();
+
+    for record in data {
+        if let Ok(serde_json::Value::Object(map)) = serde_json::to_value(record) {
+            fields.extend(map.into_iter().map(|(key, _)| key));
+        }
+    }
+
+    fields.remove("id");
+    fields.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    /// Tests that `FileJournal` appends one JSON line per entry.
+    fn file_journal_appends_lines() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!(
+            "zohoxide-journal-appends-lines-{}-{}.jsonl",
+            std::process::id(),
+            nanos
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let mut journal = FileJournal::open(&path).unwrap();
+        let entry = JournalEntry::new(
+            JournalOperation::Insert,
+            "Accounts",
+            vec![String::from("1")],
+            vec![String::from("name")],
+            None,
+        );
+        journal.append(&entry).unwrap();
+        journal.append(&entry).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<JournalEntry> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(lines, vec![entry.clone(), entry]);
+    }
+
+    #[test]
+    /// Tests that the fields of every record are collected once, without `id`.
+    fn touched_fields_skips_id() {
+        let mut first: HashMap<&str, &str> = HashMap::new();
+        first.insert("id", "1");
+        first.insert("name", "first");
+        let mut second: HashMap<&str, &str> = HashMap::new();
+        second.insert("phone", "555");
+
+        assert_eq!(touched_fields(&[first, second]), vec!["name", "phone"]);
+    }
+}
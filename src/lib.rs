@@ -41,6 +41,25 @@
 //!
 //! let account = client.get::<Account>("Accounts", "ZOHO_ID_HERE").unwrap();
 //! ```
+//!
+//! ### Features
+//!
+//! - `chrono`: add the `dates` module, whose `ZohoDate` and `ZohoDateTime` types read and write
+//!   date and date time fields in the formats Zoho uses, as [`chrono`](https://docs.rs/chrono)
+//!   values.
+//! - `csv`: add `Client::bulk_read_records`, which unzips the result of a bulk read job and
+//!   deserializes the rows of its CSV file.
+//! - `logging`: emit [`log`](https://docs.rs/log) records for every request: its method and
+//!   URL at debug level, the status of its response at info level, and API errors with an
+//!   excerpt of the raw response at error level. When trace level is enabled, the request record
+//!   is logged at trace level instead, with the abbreviated access token.
+//! - `metrics`: record [`metrics`](https://docs.rs/metrics) for every API request:
+//!   `zoho_api_requests_total` (labelled with `module`, `method` and `status`),
+//!   `zoho_api_request_duration_seconds` (labelled with `module` and `method`) and
+//!   `zoho_token_refreshes_total`. See `examples/prometheus.rs` to export them for Prometheus.
+//! - `tracing`: open a [`tracing`](https://docs.rs/tracing) span for the main `Client` methods
+//!   (`get`, `get_many`, `insert`, `update_many` and `get_new_token`), recording the module,
+//!   record ID and API domain. Tokens and credentials are never recorded.
 
 extern crate reqwest;
 extern crate serde;
@@ -49,11 +68,29 @@ extern crate serde_urlencoded;
 
 mod client;
 mod client_error;
+mod data_center;
+#[cfg(feature = "chrono")]
+pub mod dates;
+pub mod journal;
+pub mod modules;
+pub mod notifications;
+pub mod query;
+mod record_id;
+pub mod request;
 pub mod response;
+pub mod serde_helpers;
+mod shared_client;
 mod token_record;
+pub mod webhooks;
 
 pub use client::parse_params;
+pub use client::parse_params_multi;
 pub use client::Client;
 pub use client::ClientBuilder;
 pub use client_error::ClientError;
+pub use client_error::ErrorMessage;
+pub use data_center::DataCenter;
+pub use record_id::AsRecordId;
+pub use record_id::RecordId;
+pub use shared_client::SharedClient;
 pub use token_record::TokenRecord;
@@ -0,0 +1,147 @@
+//! Names of the standard Zoho CRM modules.
+//!
+//! Every `Client` method taking a module accepts any [`AsModuleName`](trait.AsModuleName.html):
+//! a [`ModuleName`](struct.ModuleName.html), a `&str` or a `String`. So the constants below can be used in place of string literals to catch
+//! typos at compile time.
+//!
+//! ### Example
+//!
+//! ```no_run
+//! # use zohoxide_crm::Client;
+//! use zohoxide_crm::modules::{self, ModuleName};
+//! # let mut client = Client::builder()
+//! # .client_id("")
+//! # .client_secret("")
+//! # .refresh_token("")
+//! # .build();
+//!
+//! let accounts = client.get_many::<serde_json::Value>(modules::ACCOUNTS, None).unwrap();
+//!
+//! let shipments = ModuleName::new("Shipments");
+//! let shipments = client.get_many::<serde_json::Value>(&shipments, None).unwrap();
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+
+/// API name of a Zoho CRM module, such as `Accounts`.
+///
+/// This is not to be confused with [`ZohoModule`](../response/struct.ZohoModule.html), the
+/// description of a module returned by the `get_modules()` method.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ModuleName(Cow<'static, str>);
+
+impl ModuleName {
+    /// Name of a custom module, or of a standard module without a constant.
+    pub fn new(name: impl Into<String>) -> ModuleName {
+        ModuleName(Cow::Owned(name.into()))
+    }
+
+    /// Name known at compile time, usable in a constant.
+    pub const fn from_static(name: &'static str) -> ModuleName {
+        ModuleName(Cow::Borrowed(name))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for ModuleName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Value that can be passed where a `Client` method expects a module name.
+///
+/// This is implemented for `ModuleName`, `str` and `String`, and for references to them, but
+/// not for [`RecordId`](../struct.RecordId.html), so a record ID cannot be passed as a module.
+///
+/// ```compile_fail
+/// # use zohoxide_crm::{Client, RecordId};
+/// # use zohoxide_crm::modules;
+/// # let mut client = Client::builder()
+/// # .client_id("")
+/// # .client_secret("")
+/// # .refresh_token("")
+/// # .build();
+/// let id = RecordId::new("554023000000634001").unwrap();
+/// client.get::<serde_json::Value>(&id, modules::ACCOUNTS);
+/// ```
+pub trait AsModuleName {
+    fn as_module_name(&self) -> &str;
+}
+
+impl AsModuleName for ModuleName {
+    fn as_module_name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsModuleName for str {
+    fn as_module_name(&self) -> &str {
+        self
+    }
+}
+
+impl AsModuleName for String {
+    fn as_module_name(&self) -> &str {
+        self
+    }
+}
+
+impl<T: AsModuleName + ?Sized> AsModuleName for &T {
+    fn as_module_name(&self) -> &str {
+        (**self).as_module_name()
+    }
+}
+
+impl fmt::Display for ModuleName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<ModuleName> for String {
+    fn from(name: ModuleName) -> String {
+        name.0.into_owned()
+    }
+}
+
+/// Leads, the prospects not yet qualified.
+pub const LEADS: ModuleName = ModuleName::from_static("Leads");
+/// Accounts, the organizations you deal with.
+pub const ACCOUNTS: ModuleName = ModuleName::from_static("Accounts");
+/// Contacts, the people of your accounts.
+pub const CONTACTS: ModuleName = ModuleName::from_static("Contacts");
+/// Deals, the sales in progress.
+pub const DEALS: ModuleName = ModuleName::from_static("Deals");
+/// Campaigns, the marketing campaigns.
+pub const CAMPAIGNS: ModuleName = ModuleName::from_static("Campaigns");
+/// Tasks, the activities to be done.
+pub const TASKS: ModuleName = ModuleName::from_static("Tasks");
+/// Events, the meetings in the calendar.
+pub const EVENTS: ModuleName = ModuleName::from_static("Events");
+/// Calls, the phone calls logged or scheduled.
+pub const CALLS: ModuleName = ModuleName::from_static("Calls");
+/// Cases, the customer support requests.
+pub const CASES: ModuleName = ModuleName::from_static("Cases");
+/// Solutions, the answers to support cases.
+pub const SOLUTIONS: ModuleName = ModuleName::from_static("Solutions");
+/// Products, the items you sell.
+pub const PRODUCTS: ModuleName = ModuleName::from_static("Products");
+/// Vendors, the suppliers of your products.
+pub const VENDORS: ModuleName = ModuleName::from_static("Vendors");
+/// Price books, the product prices for specific customers.
+pub const PRICE_BOOKS: ModuleName = ModuleName::from_static("Price_Books");
+/// Quotes, the price estimates sent to customers.
+pub const QUOTES: ModuleName = ModuleName::from_static("Quotes");
+/// Sales orders, the orders placed by customers.
+pub const SALES_ORDERS: ModuleName = ModuleName::from_static("Sales_Orders");
+/// Purchase orders, the orders placed with vendors.
+pub const PURCHASE_ORDERS: ModuleName = ModuleName::from_static("Purchase_Orders");
+/// Invoices, the bills sent to customers.
+pub const INVOICES: ModuleName = ModuleName::from_static("Invoices");
+/// Notes, the notes attached to records.
+pub const NOTES: ModuleName = ModuleName::from_static("Notes");
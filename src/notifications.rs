@@ -0,0 +1,17 @@
+//! Notifications Zoho sends to a webhook channel.
+//!
+//! The types and helpers live in the [`webhooks`](../webhooks/index.html) module, next to the
+//! signature check, and are re-exported here.
+//!
+//! ### Example
+//!
+//! ```
+//! use zohoxide_crm::notifications::{verify_token, NotificationEvent};
+//!
+//! let payload = r#"{"channel_id":"1000000068001","token":"shared-secret","module":"Leads","operation":"insert","ids":["554023000000527001"]}"#;
+//! let event: NotificationEvent = serde_json::from_str(payload).unwrap();
+//!
+//! assert!(verify_token(&event, "shared-secret"));
+//! ```
+
+pub use crate::webhooks::{verify_token, NotificationEvent};
@@ -0,0 +1,344 @@
+//! Builders for the queries understood by Zoho.
+
+use std::fmt;
+
+/// Builder of a COQL query, run with the `coql()` method.
+///
+/// Zoho API function documentation:
+/// [https://www.zoho.com/crm/developer/docs/api/v2/COQL-Overview.html](https://www.zoho.com/crm/developer/docs/api/v2/COQL-Overview.html)
+///
+/// ```
+/// use zohoxide_crm::query::CoqlQueryBuilder;
+///
+/// let query = CoqlQueryBuilder::new()
+///     .select(&["Last_Name", "Email"])
+///     .from("Contacts")
+///     .where_clause("Lead_Source = 'Advertisement'")
+///     .limit(50)
+///     .offset(100)
+///     .build();
+///
+/// assert_eq!(
+///     query,
+///     "select Last_Name, Email from Contacts where Lead_Source = 'Advertisement' limit 100, 50"
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CoqlQueryBuilder {
+    fields: Vec<String>,
+    module: String,
+    where_clause: Option<String>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+}
+
+/// Number of records Zoho returns when a COQL query has no limit.
+const DEFAULT_COQL_LIMIT: u32 = 200;
+
+impl CoqlQueryBuilder {
+    pub fn new() -> CoqlQueryBuilder {
+        CoqlQueryBuilder::default()
+    }
+
+    /// API names of the fields to return.
+    pub fn select(mut self, fields: &[&str]) -> CoqlQueryBuilder {
+        self.fields = fields.iter().map(|field| String::from(*field)).collect();
+        self
+    }
+
+    /// API name of the module to query.
+    pub fn from(mut self, module: &str) -> CoqlQueryBuilder {
+        self.module = String::from(module);
+        self
+    }
+
+    /// Condition the records must match, without the `where` keyword.
+    pub fn where_clause(mut self, clause: &str) -> CoqlQueryBuilder {
+        self.where_clause = Some(String::from(clause));
+        self
+    }
+
+    /// Maximum number of records to return, up to 200.
+    pub fn limit(mut self, limit: u32) -> CoqlQueryBuilder {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Number of records to skip.
+    pub fn offset(mut self, offset: u32) -> CoqlQueryBuilder {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Produce the query string.
+    pub fn build(&self) -> String {
+        let mut query = format!("select {} from {}", self.fields.join(", "), self.module);
+
+        if let Some(clause) = &self.where_clause {
+            query.push_str(&format!(" where {}", clause));
+        }
+
+        match (self.offset, self.limit) {
+            (Some(offset), limit) => query.push_str(&format!(
+                " limit {}, {}",
+                offset,
+                limit.unwrap_or(DEFAULT_COQL_LIMIT)
+            )),
+            (None, Some(limit)) => query.push_str(&format!(" limit {}", limit)),
+            (None, None) => {}
+        }
+
+        query
+    }
+}
+
+/// Comparison of a field with a value in a search criteria.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CriteriaOperator {
+    Equals,
+    NotEquals,
+    Contains,
+    StartsWith,
+    EndsWith,
+    GreaterThan,
+    GreaterEqual,
+    LessThan,
+    LessEqual,
+    In,
+    Between,
+}
+
+impl fmt::Display for CriteriaOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operator = match self {
+            CriteriaOperator::Equals => "equals",
+            CriteriaOperator::NotEquals => "not_equal",
+            CriteriaOperator::Contains => "contains",
+            CriteriaOperator::StartsWith => "starts_with",
+            CriteriaOperator::EndsWith => "ends_with",
+            CriteriaOperator::GreaterThan => "greater_than",
+            CriteriaOperator::GreaterEqual => "greater_equal",
+            CriteriaOperator::LessThan => "less_than",
+            CriteriaOperator::LessEqual => "less_equal",
+            CriteriaOperator::In => "in",
+            CriteriaOperator::Between => "between",
+        };
+
+        write!(f, "{}", operator)
+    }
+}
+
+/// Builder of the `criteria` parameter of a search.
+///
+/// Zoho API function documentation:
+/// [https://www.zoho.com/crm/developer/docs/api/v2/search-records.html](https://www.zoho.com/crm/developer/docs/api/v2/search-records.html)
+///
+/// Conditions are combined from left to right. Two conditions not separated by `and()` or
+/// `or()` are combined with `and`. Parentheses and commas in values are escaped, so conditions
+/// on several values are added with `add_condition_values()`.
+///
+/// ```
+/// use zohoxide_crm::query::{CriteriaBuilder, CriteriaOperator};
+///
+/// let criteria = CriteriaBuilder::new()
+///     .add_condition("Last_Name", CriteriaOperator::Equals, "Smith")
+///     .or()
+///     .add_condition("Email", CriteriaOperator::StartsWith, "smith")
+///     .build();
+///
+/// assert_eq!(criteria, "((Last_Name:equals:Smith)or(Email:starts_with:smith))");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CriteriaBuilder {
+    expression: String,
+    connective: Option<&'static str>,
+}
+
+impl CriteriaBuilder {
+    pub fn new() -> CriteriaBuilder {
+        CriteriaBuilder::default()
+    }
+
+    /// Add a condition, combined with the previous ones by the last `and()` or `or()`.
+    pub fn add_condition(
+        self,
+        field: &str,
+        operator: CriteriaOperator,
+        value: &str,
+    ) -> CriteriaBuilder {
+        self.push_condition(field, operator, escape_criteria_value(value))
+    }
+
+    /// Add a condition on several values, as used by `In` and `Between`.
+    ///
+    /// Each value is escaped, then the values are joined with commas. `Between` takes the lower
+    /// and the upper bound, in that order.
+    ///
+    /// ```
+    /// use zohoxide_crm::query::{CriteriaBuilder, CriteriaOperator};
+    ///
+    /// let criteria = CriteriaBuilder::new()
+    ///     .add_condition_values("Lead_Source", CriteriaOperator::In, &["Web", "Trade Show"])
+    ///     .build();
+    ///
+    /// assert_eq!(criteria, "(Lead_Source:in:Web,Trade Show)");
+    /// ```
+    pub fn add_condition_values(
+        self,
+        field: &str,
+        operator: CriteriaOperator,
+        values: &[&str],
+    ) -> CriteriaBuilder {
+        let values = values
+            .iter()
+            .map(|value| escape_criteria_value(value))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        self.push_condition(field, operator, values)
+    }
+
+    /// Add a condition on an already escaped value.
+    fn push_condition(
+        mut self,
+        field: &str,
+        operator: CriteriaOperator,
+        value: String,
+    ) -> CriteriaBuilder {
+        let condition = format!("({}:{}:{})", field, operator, value);
+
+        self.expression = if self.expression.is_empty() {
+            condition
+        } else {
+            let connective = self.connective.unwrap_or("and");
+            format!("({}{}{})", self.expression, connective, condition)
+        };
+        self.connective = None;
+
+        self
+    }
+
+    /// Require both the previous and the next condition to match.
+    pub fn and(mut self) -> CriteriaBuilder {
+        self.connective = Some("and");
+        self
+    }
+
+    /// Require either the previous or the next condition to match.
+    pub fn or(mut self) -> CriteriaBuilder {
+        self.connective = Some("or");
+        self
+    }
+
+    /// Produce the criteria string.
+    pub fn build(&self) -> String {
+        self.expression.clone()
+    }
+}
+
+/// Escape the characters that have a meaning in a criteria.
+fn escape_criteria_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for character in value.chars() {
+        if matches!(character, '\\' | '(' | ')' | ',') {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// Tests that a query without a condition, limit or offset only selects fields from the module.
+    fn coql_query_without_options() {
+        let query = CoqlQueryBuilder::new()
+            .select(&["Last_Name"])
+            .from("Leads")
+            .build();
+
+        assert_eq!(query, "select Last_Name from Leads");
+    }
+
+    #[test]
+    /// Tests that an offset without a limit uses the default limit of 200.
+    fn coql_query_offset_without_limit() {
+        let query = CoqlQueryBuilder::new()
+            .select(&["Last_Name"])
+            .from("Leads")
+            .offset(200)
+            .build();
+
+        assert_eq!(query, "select Last_Name from Leads limit 200, 200");
+    }
+
+    #[test]
+    /// Tests that a single condition is wrapped in parentheses.
+    fn criteria_single_condition() {
+        let criteria = CriteriaBuilder::new()
+            .add_condition("Annual_Revenue", CriteriaOperator::GreaterThan, "1000")
+            .build();
+
+        assert_eq!(criteria, "(Annual_Revenue:greater_than:1000)");
+    }
+
+    #[test]
+    /// Tests that conditions are combined from left to right, with `and` by default.
+    fn criteria_combined_left_to_right() {
+        let criteria = CriteriaBuilder::new()
+            .add_condition("A", CriteriaOperator::Equals, "1")
+            .or()
+            .add_condition("B", CriteriaOperator::Equals, "2")
+            .add_condition("C", CriteriaOperator::NotEquals, "3")
+            .build();
+
+        assert_eq!(criteria, "(((A:equals:1)or(B:equals:2))and(C:not_equal:3))");
+    }
+
+    #[test]
+    /// Tests that commas and parentheses in a value are escaped.
+    fn criteria_escapes_values() {
+        let criteria = CriteriaBuilder::new()
+            .add_condition(
+                "Account_Name",
+                CriteriaOperator::Equals,
+                "Smith, Jones (UK)",
+            )
+            .build();
+
+        assert_eq!(criteria, r"(Account_Name:equals:Smith\, Jones \(UK\))");
+    }
+
+    #[test]
+    /// Tests that the values of an `in` condition are escaped one by one and joined by commas.
+    fn criteria_in_values() {
+        let criteria = CriteriaBuilder::new()
+            .add_condition_values(
+                "Account_Name",
+                CriteriaOperator::In,
+                &["Smith, Jones", "Acme (UK)"],
+            )
+            .build();
+
+        assert_eq!(criteria, r"(Account_Name:in:Smith\, Jones,Acme \(UK\))");
+    }
+
+    #[test]
+    /// Tests that a `between` condition holds both bounds.
+    fn criteria_between_values() {
+        let criteria = CriteriaBuilder::new()
+            .add_condition_values(
+                "Annual_Revenue",
+                CriteriaOperator::Between,
+                &["1000", "5000"],
+            )
+            .build();
+
+        assert_eq!(criteria, "(Annual_Revenue:between:1000,5000)");
+    }
+}
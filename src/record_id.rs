@@ -0,0 +1,97 @@
+use crate::client_error::ClientError;
+use std::fmt;
+
+/// ID of a Zoho record, checked to be made of 18 digits.
+///
+/// The `Client` methods taking a record ID accept any [`AsRecordId`](trait.AsRecordId.html):
+/// a `RecordId`, a `&str` or a `String`. A `RecordId` cannot be passed where a module name is
+/// expected, nor a [`ModuleName`](modules/struct.ModuleName.html) where a record ID is.
+///
+/// ### Example
+///
+/// ```
+/// use zohoxide_crm::RecordId;
+///
+/// let id = RecordId::new("554023000000634001").unwrap();
+/// assert_eq!(id.as_str(), "554023000000634001");
+///
+/// assert!(RecordId::new("Accounts").is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RecordId(String);
+
+impl RecordId {
+    /// Check that `id` looks like a Zoho record ID, made of 18 digits.
+    pub fn new(id: &str) -> Result<RecordId, ClientError> {
+        if id.len() != 18 || !id.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(ClientError::from(format!("Invalid record ID \"{}\"", id)));
+        }
+
+        Ok(RecordId(String::from(id)))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for RecordId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Value that can be passed where a `Client` method expects a record ID.
+///
+/// This is implemented for `RecordId`, `str` and `String`, and for references to them, but not
+/// for [`ModuleName`](modules/struct.ModuleName.html), so a module cannot be passed as an ID.
+///
+/// ```compile_fail
+/// # use zohoxide_crm::Client;
+/// # use zohoxide_crm::modules;
+/// # let mut client = Client::builder()
+/// # .client_id("")
+/// # .client_secret("")
+/// # .refresh_token("")
+/// # .build();
+/// client.get::<serde_json::Value>("554023000000634001", modules::ACCOUNTS);
+/// ```
+pub trait AsRecordId {
+    fn as_record_id(&self) -> &str;
+}
+
+impl AsRecordId for RecordId {
+    fn as_record_id(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRecordId for str {
+    fn as_record_id(&self) -> &str {
+        self
+    }
+}
+
+impl AsRecordId for String {
+    fn as_record_id(&self) -> &str {
+        self
+    }
+}
+
+impl<T: AsRecordId + ?Sized> AsRecordId for &T {
+    fn as_record_id(&self) -> &str {
+        (**self).as_record_id()
+    }
+}
+
+impl fmt::Display for RecordId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<RecordId> for String {
+    fn from(id: RecordId) -> String {
+        id.0
+    }
+}
@@ -0,0 +1,800 @@
+//! Various request objects sent to Zoho.
+
+use crate::client_error::ClientError;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+use typed_builder::TypedBuilder;
+
+/// Automation Zoho can run when records are written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum Trigger {
+    #[serde(rename = "workflow")]
+    Workflow,
+    #[serde(rename = "approval")]
+    ApprovalProcess,
+    #[serde(rename = "blueprint")]
+    Blueprint,
+}
+
+/// Extra options sent alongside the records of a write request.
+///
+/// ```
+/// use zohoxide_crm::request::WriteOptions;
+///
+/// // suppress workflows, approval processes and blueprints
+/// let options = WriteOptions {
+///     trigger: Some(vec![]),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct WriteOptions {
+    /// Automation to run for the written records. `None` lets Zoho run all of it, while an
+    /// empty list suppresses all of it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<Vec<Trigger>>,
+
+    /// ID of the assignment rule to run for inserted records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lar_id: Option<String>,
+
+    /// Pause between the batches of a write that is split into several requests, to stay
+    /// within Zoho's rate limits. This is not sent to Zoho.
+    #[serde(skip)]
+    pub batch_delay: Option<Duration>,
+
+    /// Number of times a failed batch is sent again before giving up. This is not sent to Zoho.
+    ///
+    /// Only network errors, server errors (5xx) and rate limits (429) are retried. Errors about
+    /// the records themselves, such as `INVALID_DATA`, are returned right away.
+    ///
+    /// Retrying inserts may create duplicate records: when the connection drops or times out
+    /// after Zoho wrote a batch, the batch is sent again. Prefer upserts on an external ID when
+    /// retrying writes that create records.
+    #[serde(skip)]
+    pub batch_retries: u32,
+
+    /// Delay before the first retry of a failed batch, doubled for each following retry.
+    /// Defaults to one second. This is not sent to Zoho.
+    #[serde(skip)]
+    pub batch_retry_delay: Option<Duration>,
+}
+
+/// Body of a write request: the records in a `data` field, next to the write options.
+#[derive(Serialize)]
+pub(crate) struct WriteBody<'a, T> {
+    pub data: Vec<T>,
+
+    #[serde(flatten)]
+    pub options: &'a WriteOptions,
+}
+
+/// Parameters of a lead conversion, sent with the `convert_lead()` method.
+///
+/// Zoho API function documentation:
+/// [https://www.zoho.com/crm/developer/docs/api/v2/convert-lead.html](https://www.zoho.com/crm/developer/docs/api/v2/convert-lead.html)
+///
+/// ```
+/// use zohoxide_crm::request::ConvertLeadParams;
+///
+/// let params = ConvertLeadParams {
+///     overwrite: Some(true),
+///     accounts: Some(String::from("ZOHO_ACCOUNT_ID_HERE")),
+///     deals: Some(serde_json::json!({
+///         "Deal_Name": "Robert",
+///         "Closing_Date": "2016-03-30",
+///         "Stage": "Closed Won",
+///     })),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ConvertLeadParams {
+    /// Overwrite the account or contact the lead is merged into.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overwrite: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_lead_owner: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_new_entity_owner: Option<bool>,
+
+    /// ID of an existing account to associate the lead with.
+    #[serde(rename = "Accounts", skip_serializing_if = "Option::is_none")]
+    pub accounts: Option<String>,
+
+    /// ID of an existing contact to associate the lead with.
+    #[serde(rename = "Contacts", skip_serializing_if = "Option::is_none")]
+    pub contacts: Option<String>,
+
+    /// Fields of a deal to create along with the conversion.
+    #[serde(rename = "Deals", skip_serializing_if = "Option::is_none")]
+    pub deals: Option<serde_json::Value>,
+}
+
+/// A note to attach to any record, sent with the `insert_notes()` method.
+///
+/// ```
+/// use zohoxide_crm::request::NoteInput;
+///
+/// let note = NoteInput {
+///     parent_id: String::from("ZOHO_ID_HERE"),
+///     se_module: String::from("Leads"),
+///     title: Some(String::from("Call")),
+///     content: String::from("Left a voicemail"),
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct NoteInput {
+    /// ID of the record the note is attached to.
+    #[serde(rename = "Parent_Id")]
+    pub parent_id: String,
+
+    /// API name of the module of the parent record.
+    pub se_module: String,
+
+    #[serde(rename = "Note_Title", skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(rename = "Note_Content")]
+    pub content: String,
+}
+
+/// Records to export with a bulk read job, sent with the `bulk_read_create()` method.
+///
+/// Zoho API function documentation:
+/// [https://www.zoho.com/crm/developer/docs/api/v2/bulk-read/create-job.html](https://www.zoho.com/crm/developer/docs/api/v2/bulk-read/create-job.html)
+///
+/// ```
+/// use zohoxide_crm::request::BulkReadQuery;
+///
+/// let query = BulkReadQuery {
+///     module_api_name: String::from("Contacts"),
+///     fields: Some(vec![String::from("Last_Name"), String::from("Email")]),
+///     criteria: Some(serde_json::json!({
+///         "api_name": "Lead_Source",
+///         "comparator": "equal",
+///         "value": "Advertisement",
+///     })),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct BulkReadQuery {
+    #[serde(rename = "module")]
+    pub module_api_name: String,
+
+    /// API names of the fields to export. `None` exports all of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<String>>,
+
+    /// Criteria the exported records must match, in Zoho's JSON criteria format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub criteria: Option<serde_json::Value>,
+
+    /// Page of 200,000 records to export, starting at 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+}
+
+/// Format of the file exported by a bulk read job.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkReadFileType {
+    /// A zipped CSV file.
+    #[default]
+    Csv,
+
+    /// An iCalendar file, only supported for the `Events` module.
+    Ics,
+}
+
+/// Bulk read job sent with the `bulk_read_create()` method, for when more than the
+/// [`BulkReadQuery`] is needed.
+///
+/// A `BulkReadQuery` converts into a request exporting a CSV file.
+///
+/// ```
+/// use zohoxide_crm::request::{BulkReadFileType, BulkReadQuery, BulkReadRequest};
+///
+/// let request = BulkReadRequest {
+///     query: BulkReadQuery {
+///         module_api_name: String::from("Events"),
+///         ..Default::default()
+///     },
+///     file_type: Some(BulkReadFileType::Ics),
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct BulkReadRequest {
+    pub query: BulkReadQuery,
+
+    /// Format of the exported file. `None` lets Zoho export a CSV file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_type: Option<BulkReadFileType>,
+}
+
+impl From<BulkReadQuery> for BulkReadRequest {
+    fn from(query: BulkReadQuery) -> BulkReadRequest {
+        BulkReadRequest {
+            query,
+            file_type: None,
+        }
+    }
+}
+
+/// Notification channel sent with the `create_webhook()` and `update_webhook()` methods.
+///
+/// Zoho API function documentation:
+/// [https://www.zoho.com/crm/developer/docs/api/v2/notifications/enable.html](https://www.zoho.com/crm/developer/docs/api/v2/notifications/enable.html)
+///
+/// ```
+/// use zohoxide_crm::request::WebhookConfig;
+///
+/// let config = WebhookConfig {
+///     channel_id: 1000000068001,
+///     events: vec![String::from("Leads.create"), String::from("Leads.edit")],
+///     notify_url: String::from("https://example.com/zoho/callback"),
+///     token: Some(String::from("shared-secret")),
+///     channel_expiry: None,
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct WebhookConfig {
+    /// ID chosen by the caller to identify the channel.
+    pub channel_id: u64,
+
+    /// Events to be notified of, such as `Leads.create` or `Deals.all`.
+    pub events: Vec<String>,
+
+    /// URL Zoho sends the notifications to.
+    pub notify_url: String,
+
+    /// Value Zoho sends back with every notification, to authenticate it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+
+    /// Time at which the channel expires, in ISO 8601 format. Zoho defaults to one hour and
+    /// accepts at most one day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_expiry: Option<String>,
+}
+
+/// Sender or recipient of an email sent with the `send_mail()` method.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EmailAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+
+    pub email: String,
+}
+
+impl EmailAddress {
+    /// Address without a display name.
+    pub fn new(email: impl Into<String>) -> EmailAddress {
+        EmailAddress {
+            user_name: None,
+            email: email.into(),
+        }
+    }
+
+    /// Address with a display name.
+    pub fn with_name(user_name: impl Into<String>, email: impl Into<String>) -> EmailAddress {
+        EmailAddress {
+            user_name: Some(user_name.into()),
+            email: email.into(),
+        }
+    }
+}
+
+/// Filter of the `get_users()` method.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub enum UserType {
+    #[default]
+    AllUsers,
+    ActiveUsers,
+    DeactiveUsers,
+    ConfirmedUsers,
+    NotConfirmedUsers,
+    DeletedUsers,
+    ActiveConfirmedUsers,
+    AdminUsers,
+    ActiveConfirmedAdmins,
+    CurrentUser,
+}
+
+/// Format of the content of an email.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MailFormat {
+    #[default]
+    Html,
+    Text,
+}
+
+/// Email sent on behalf of a record with the `send_mail()` method.
+///
+/// Zoho API function documentation:
+/// [https://www.zoho.com/crm/developer/docs/api/v2/send-mail.html](https://www.zoho.com/crm/developer/docs/api/v2/send-mail.html)
+///
+/// The `from` address must be verified in Zoho. Either `content` or `template_id` must be set.
+///
+/// ```
+/// use zohoxide_crm::request::{EmailAddress, SendMailRequest};
+///
+/// let mail = SendMailRequest::builder()
+///     .from(EmailAddress::with_name("Patricia Boyle", "patricia@example.com"))
+///     .to(vec![EmailAddress::new("john@example.com")])
+///     .subject("Your quote")
+///     .content(Some(String::from("<p>Please find your quote attached.</p>")))
+///     .build();
+/// ```
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+#[builder(doc, field_defaults(default, setter(into)))]
+pub struct SendMailRequest {
+    #[builder(!default)]
+    pub from: EmailAddress,
+
+    #[builder(!default)]
+    pub to: Vec<EmailAddress>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cc: Vec<EmailAddress>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub bcc: Vec<EmailAddress>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<EmailAddress>,
+
+    #[builder(!default)]
+    pub subject: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    pub mail_format: MailFormat,
+
+    /// ID of the email template to use instead of `content`.
+    #[serde(
+        rename = "template",
+        serialize_with = "serialize_template_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub template_id: Option<String>,
+}
+
+/// Zoho expects the template as an object holding its ID.
+fn serialize_template_id<S: serde::Serializer>(
+    template_id: &Option<String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct Template<'a> {
+        id: &'a str,
+    }
+
+    match template_id {
+        Some(id) => Template { id }.serialize(serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Access given to a user a record is shared with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SharePermission {
+    #[default]
+    ReadOnly,
+    ReadWrite,
+    FullAccess,
+}
+
+/// User to share a record with, sent with the `share_record()` and `update_record_shares()`
+/// methods.
+///
+/// ```
+/// use zohoxide_crm::request::{SharePermission, ShareInput};
+///
+/// let share = ShareInput {
+///     user_id: String::from("554023000000235011"),
+///     permission: SharePermission::ReadWrite,
+///     share_related_records: true,
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ShareInput {
+    pub user_id: String,
+    pub permission: SharePermission,
+
+    /// Whether to also share the records of the related lists, such as notes and attachments.
+    pub share_related_records: bool,
+}
+
+impl Serialize for ShareInput {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct User<'a> {
+            id: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct Share<'a> {
+            user: User<'a>,
+            permission: SharePermission,
+            share_related_records: bool,
+        }
+
+        Share {
+            user: User { id: &self.user_id },
+            permission: self.permission,
+            share_related_records: self.share_related_records,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Order in which records are sorted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum SortOrder {
+    #[serde(rename = "asc")]
+    Ascending,
+    #[serde(rename = "desc")]
+    Descending,
+}
+
+impl SortOrder {
+    /// Value of the order as expected by Zoho.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "asc",
+            SortOrder::Descending => "desc",
+        }
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Sorting of the records returned by the `get_many()` method.
+///
+/// It serializes to the `sort_by` and `sort_order` parameters:
+///
+/// ```
+/// use zohoxide_crm::parse_params;
+/// use zohoxide_crm::request::{SortOrder, SortParams};
+///
+/// let sort = SortParams {
+///     field: String::from("Created_Time"),
+///     order: SortOrder::Descending,
+/// };
+///
+/// assert_eq!(parse_params(&sort).unwrap(), "sort_by=Created_Time&sort_order=desc");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SortParams {
+    #[serde(rename = "sort_by")]
+    pub field: String,
+
+    #[serde(rename = "sort_order")]
+    pub order: SortOrder,
+}
+
+/// Add the `sort_by` and `sort_order` parameters of `sort` to `params`.
+///
+/// ```
+/// # use std::collections::HashMap;
+/// use zohoxide_crm::parse_params;
+/// use zohoxide_crm::request::{with_sort, SortOrder, SortParams};
+///
+/// let sort = SortParams {
+///     field: String::from("Last_Name"),
+///     order: SortOrder::Ascending,
+/// };
+///
+/// let mut params: HashMap<&str, &str> = HashMap::new();
+/// params.insert("page", "2");
+/// with_sort(&mut params, &sort);
+///
+/// let params = parse_params(params).unwrap();
+/// ```
+pub fn with_sort<'a>(params: &mut HashMap<&'a str, &'a str>, sort: &'a SortParams) {
+    params.insert("sort_by", &sort.field);
+    params.insert("sort_order", sort.order.as_str());
+}
+
+/// Maximum number of records Zoho returns per page.
+const MAX_PER_PAGE: u32 = 200;
+
+/// Builder of the parameters of the `get_many()` method, validating their values.
+///
+/// ```
+/// use zohoxide_crm::parse_params;
+/// use zohoxide_crm::request::{GetManyParamsBuilder, SortOrder};
+///
+/// let params = GetManyParamsBuilder::new()
+///     .page(2)
+///     .per_page(50)
+///     .sort_by("Created_Time")
+///     .sort_order(SortOrder::Descending)
+///     .fields(&["Account_Name", "Phone"])
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(
+///     parse_params(&params).unwrap(),
+///     "page=2&per_page=50&sort_by=Created_Time&sort_order=desc&fields=Account_Name%2CPhone"
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GetManyParamsBuilder {
+    page: Option<u32>,
+    per_page: Option<u32>,
+    cvid: Option<String>,
+    sort_by: Option<String>,
+    sort_order: Option<SortOrder>,
+    fields: Option<Vec<String>>,
+}
+
+impl GetManyParamsBuilder {
+    pub fn new() -> GetManyParamsBuilder {
+        GetManyParamsBuilder::default()
+    }
+
+    /// Page to fetch, starting at 1.
+    pub fn page(mut self, page: u32) -> GetManyParamsBuilder {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of records per page, from 1 to 200.
+    pub fn per_page(mut self, per_page: u32) -> GetManyParamsBuilder {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// ID of the custom view whose records are fetched.
+    pub fn cvid(mut self, cvid: &str) -> GetManyParamsBuilder {
+        self.cvid = Some(String::from(cvid));
+        self
+    }
+
+    /// API name of the field to sort the records by.
+    pub fn sort_by(mut self, field: &str) -> GetManyParamsBuilder {
+        self.sort_by = Some(String::from(field));
+        self
+    }
+
+    pub fn sort_order(mut self, order: SortOrder) -> GetManyParamsBuilder {
+        self.sort_order = Some(order);
+        self
+    }
+
+    /// Sort the records by `sort.field`, in `sort.order`.
+    pub fn sort(self, sort: SortParams) -> GetManyParamsBuilder {
+        self.sort_by(&sort.field).sort_order(sort.order)
+    }
+
+    /// API names of the fields to return.
+    pub fn fields(mut self, fields: &[&str]) -> GetManyParamsBuilder {
+        self.fields = Some(fields.iter().map(|field| String::from(*field)).collect());
+        self
+    }
+
+    /// Validate the parameters.
+    pub fn build(&self) -> Result<GetManyParams, ClientError> {
+        if self.page == Some(0) {
+            return Err(ClientError::from("page must be at least 1"));
+        }
+
+        if let Some(per_page) = self.per_page {
+            if !(1..=MAX_PER_PAGE).contains(&per_page) {
+                return Err(ClientError::from(format!(
+                    "per_page must be between 1 and {}",
+                    MAX_PER_PAGE
+                )));
+            }
+        }
+
+        Ok(GetManyParams {
+            page: self.page,
+            per_page: self.per_page,
+            cvid: self.cvid.clone(),
+            sort_by: self.sort_by.clone(),
+            sort_order: self.sort_order,
+            fields: self.fields.clone(),
+        })
+    }
+}
+
+/// Typed parameters of the `get_many()` method, to pass to
+/// [`parse_params`](../fn.parse_params.html).
+///
+/// They are usually built with [`GetManyParamsBuilder`](struct.GetManyParamsBuilder.html),
+/// which validates their values. Parameters left to `None` are not sent.
+///
+/// ```
+/// use zohoxide_crm::parse_params;
+/// use zohoxide_crm::request::{GetManyParams, SortOrder};
+///
+/// let params = GetManyParams {
+///     page: Some(2),
+///     sort_order: Some(SortOrder::Descending),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(parse_params(&params).unwrap(), "page=2&sort_order=desc");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct GetManyParams {
+    /// Page to fetch, starting at 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// Number of records per page, from 1 to 200.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+
+    /// ID of the custom view whose records are fetched.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cvid: Option<String>,
+
+    /// API name of the field to sort the records by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<SortOrder>,
+
+    /// API names of the fields to return.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_comma_separated"
+    )]
+    pub fields: Option<Vec<String>>,
+}
+
+/// Serialize a list as a single comma-separated value, as Zoho expects in query strings.
+fn serialize_comma_separated<S: serde::Serializer>(
+    values: &Option<Vec<String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match values {
+        Some(values) => serializer.serialize_str(&values.join(",")),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Maximum number of sub-requests Zoho accepts in a composite request.
+pub const MAX_COMPOSITE_SUB_REQUESTS: usize = 5;
+
+/// Batch of API calls sent in a single request with the `composite()` method.
+///
+/// Sub-requests run in the order they are added, unless parallel execution is enabled. They
+/// are given the IDs `"1"`, `"2"`, and so on, which later sub-requests can use to refer to the
+/// results of earlier ones, such as `@{1:$.data[0].details.id}`.
+///
+/// ```
+/// use zohoxide_crm::request::CompositeRequest;
+///
+/// let request = CompositeRequest::new()
+///     .rollback_on_failure(true)
+///     .sub_request(
+///         "POST",
+///         "/crm/v3/Accounts",
+///         Some(serde_json::json!({"data": [{"Account_Name": "Zylker"}]})),
+///     )
+///     .sub_request(
+///         "POST",
+///         "/crm/v3/Contacts",
+///         Some(serde_json::json!({
+///             "data": [{"Last_Name": "Boyle", "Account_Name": {"id": "@{1:$.data[0].details.id}"}}]
+///         })),
+///     );
+///
+/// assert_eq!(request.len(), 2);
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CompositeRequest {
+    #[serde(rename = "rollback_on_fail")]
+    rollback_on_failure: bool,
+    parallel_execution: bool,
+    #[serde(rename = "__composite_requests")]
+    sub_requests: Vec<CompositeSubRequest>,
+}
+
+/// A single API call of a [`CompositeRequest`](struct.CompositeRequest.html).
+#[derive(Clone, Debug, Serialize)]
+struct CompositeSubRequest {
+    sub_request_id: String,
+    method: String,
+    uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<serde_json::Value>,
+}
+
+impl CompositeRequest {
+    pub fn new() -> CompositeRequest {
+        CompositeRequest::default()
+    }
+
+    /// Undo the sub-requests already run when one of them fails. Zoho only supports it when
+    /// parallel execution is disabled.
+    pub fn rollback_on_failure(mut self, rollback_on_failure: bool) -> CompositeRequest {
+        self.rollback_on_failure = rollback_on_failure;
+        self
+    }
+
+    /// Run the sub-requests in parallel, when they do not depend on each other.
+    pub fn parallel_execution(mut self, parallel_execution: bool) -> CompositeRequest {
+        self.parallel_execution = parallel_execution;
+        self
+    }
+
+    /// Add a sub-request, with its HTTP method, the path of the API function such as
+    /// `/crm/v3/Accounts`, and its JSON body if any.
+    pub fn sub_request(
+        mut self,
+        method: &str,
+        uri: &str,
+        body: Option<serde_json::Value>,
+    ) -> CompositeRequest {
+        self.sub_requests.push(CompositeSubRequest {
+            sub_request_id: (self.sub_requests.len() + 1).to_string(),
+            method: method.to_ascii_uppercase(),
+            uri: String::from(uri),
+            body,
+        });
+        self
+    }
+
+    /// Number of sub-requests.
+    pub fn len(&self) -> usize {
+        self.sub_requests.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sub_requests.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// Tests that the builder rejects out of range pages and page sizes.
+    fn get_many_params_builder_validation() {
+        assert!(GetManyParamsBuilder::new().per_page(0).build().is_err());
+        assert!(GetManyParamsBuilder::new().per_page(201).build().is_err());
+        assert!(GetManyParamsBuilder::new().page(0).build().is_err());
+        assert_eq!(
+            GetManyParamsBuilder::new().per_page(200).build().unwrap(),
+            GetManyParams {
+                per_page: Some(200),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    /// Tests that unset parameters are left out of the query string.
+    fn get_many_params_skips_none() {
+        let params = GetManyParamsBuilder::new()
+            .cvid("554023000000087501")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            crate::parse_params(&params).unwrap(),
+            "cvid=554023000000087501"
+        );
+        assert_eq!(crate::parse_params(GetManyParams::default()).unwrap(), "");
+    }
+}
@@ -1,12 +1,30 @@
 //! Various response objects returned from Zoho.
 
+use crate::client_error::ClientError;
+use crate::serde_helpers::{deserialize_optional_zoho_id, deserialize_zoho_id};
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::fmt;
+#[cfg(not(feature = "chrono"))]
+use std::time::UNIX_EPOCH;
+use std::time::{Duration, SystemTime};
 
 /// Wrapper around a successful response using the `get()` method.
 #[derive(Debug, Deserialize)]
 pub struct ApiGetResponse<T> {
     pub data: Vec<T>,
+
+    /// Top-level fields of the response other than `data`, such as metadata Zoho added later.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl<T> ApiGetResponse<T> {
+    /// Top-level field `key` of the response, other than `data`.
+    pub fn raw_field(&self, key: &str) -> Option<&serde_json::Value> {
+        self.extra.get(key)
+    }
 }
 
 /// Wrapper around a successful response using the `get_many()` method.
@@ -16,15 +34,79 @@ pub struct ApiGetResponse<T> {
 #[derive(Debug, Deserialize)]
 pub struct ApiGetManyResponse<T> {
     pub data: Vec<T>,
+
+    /// Pagination info, left to its default for endpoints that do not send it.
+    #[serde(default)]
     pub info: ApiGetManyResponseInfo,
+
+    /// Top-level fields of the response other than `data` and `info`, such as metadata Zoho
+    /// added later.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl<T> Default for ApiGetManyResponse<T> {
+    fn default() -> Self {
+        ApiGetManyResponse {
+            data: Vec::new(),
+            info: ApiGetManyResponseInfo::default(),
+            extra: HashMap::new(),
+        }
+    }
+}
+
+impl<T> ApiGetManyResponse<T> {
+    /// Take the records, dropping the pagination info.
+    pub fn into_data(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Top-level field `key` of the response, other than `data` and `info`.
+    pub fn raw_field(&self, key: &str) -> Option<&serde_json::Value> {
+        self.extra.get(key)
+    }
+
+    /// Pagination info of the page, with every field at its default when Zoho omitted it.
+    pub fn info(&self) -> &ApiGetManyResponseInfo {
+        &self.info
+    }
+
+    /// Number of records on this page.
+    ///
+    /// Unlike `info.count`, this does not depend on Zoho sending pagination info.
+    pub fn total_on_page(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Whether Zoho has more records after this page.
+    pub fn has_more(&self) -> bool {
+        self.info.more_records
+    }
+
+    /// Number of this page, starting at 1, or `None` when Zoho did not send it.
+    pub fn page(&self) -> Option<usize> {
+        match self.info.page {
+            0 => None,
+            page => Some(page),
+        }
+    }
 }
 
 /// Meta data sent back with the `get_many()` method.
-#[derive(Debug, Deserialize)]
+///
+/// Fields missing from the response, as with endpoints that do not paginate by page such as
+/// COQL queries, are left to their default.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
 pub struct ApiGetManyResponseInfo {
+    /// Number of records on this page. Zoho v2 does not send the total number of records.
     pub count: usize,
+
     pub more_records: bool,
+
+    /// Number of this page, starting at 1, or 0 when Zoho did not send it.
     pub page: usize,
+
     pub per_page: usize,
 }
 
@@ -49,6 +131,16 @@ pub struct ApiSuccessResponseDataItem {
     pub status: String,
 }
 
+impl ApiSuccessResponseDataItem {
+    /// ID of the record this item refers to, when Zoho returned one.
+    pub fn record_id(&self) -> Option<&str> {
+        match &self.details {
+            ResponseDataItemDetails::Success(details) => Some(&details.id),
+            ResponseDataItemDetails::Error(details) => details.id.as_deref(),
+        }
+    }
+}
+
 // The order of the variants matter here, because `serde` will try to match each variant,
 // starting from the top.
 #[derive(Debug, Deserialize)]
@@ -61,6 +153,8 @@ pub enum ResponseDataItemDetails {
 #[derive(Debug, Deserialize)]
 pub struct ResponseDataItemDetailsError {
     pub api_name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_zoho_id")]
+    pub id: Option<String>,
     pub expected_data_type: Option<String>,
     pub index: Option<String>,
 }
@@ -74,9 +168,16 @@ pub struct ResponseDataItemDetailsSuccess {
     #[serde(alias = "Modified_Time")]
     pub modified_time: String,
 
+    #[serde(alias = "Modified_By")]
+    pub modified_by: Option<UserRef>,
+
     #[serde(alias = "Created_Time")]
     pub created_time: String,
 
+    #[serde(alias = "Created_By")]
+    pub created_by: Option<UserRef>,
+
+    #[serde(deserialize_with = "deserialize_zoho_id")]
     pub id: String,
 }
 
@@ -97,6 +198,10 @@ pub struct ApiErrorResponse {
 
     #[allow(dead_code)]
     pub status: String,
+
+    /// More information on the error, whose shape depends on its `code`.
+    #[serde(default)]
+    pub details: ApiErrorDetails,
 }
 
 impl fmt::Display for ApiErrorResponse {
@@ -104,3 +209,1409 @@ impl fmt::Display for ApiErrorResponse {
         write!(f, "[{}] {}", self.code, self.message)
     }
 }
+
+/// Details of an [`ApiErrorResponse`](struct.ApiErrorResponse.html).
+///
+/// Zoho sends a different object for each kind of error. The known shapes are recognized from
+/// their keys, and anything else is kept in `Unknown`, such as the details of API limit errors.
+#[derive(Debug, Default, PartialEq)]
+pub enum ApiErrorDetails {
+    /// No details were sent, or an empty object.
+    #[default]
+    Empty,
+
+    /// Fields that are missing or have an invalid value, such as for `MANDATORY_NOT_FOUND` or
+    /// `INVALID_DATA`.
+    FieldErrors(Vec<FieldError>),
+
+    /// The existing record a `DUPLICATE_DATA` error conflicts with.
+    DuplicateRecord {
+        id: String,
+    },
+
+    Unknown(serde_json::Value),
+}
+
+/// A field reported in the details of an API error.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct FieldError {
+    pub api_name: String,
+    pub expected_data_type: Option<String>,
+
+    /// Path of the value in the request body, such as `$.data[0].Email`.
+    pub json_path: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for ApiErrorDetails {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+
+        // Older versions of the API send the ID of the duplicate at the top level, newer ones
+        // in a `duplicate_record` object.
+        let duplicate_id = value
+            .pointer("/duplicate_record/id")
+            .or_else(|| value.get("api_name").and(value.get("id")));
+        if let Some(id) = duplicate_id {
+            if let Ok(id) = deserialize_zoho_id(id) {
+                return Ok(ApiErrorDetails::DuplicateRecord { id });
+            }
+        }
+
+        let details = match &value {
+            serde_json::Value::Null => ApiErrorDetails::Empty,
+            serde_json::Value::Object(object) if object.is_empty() => ApiErrorDetails::Empty,
+            serde_json::Value::Object(object) if object.contains_key("api_name") => {
+                match FieldError::deserialize(&value) {
+                    Ok(field) => ApiErrorDetails::FieldErrors(vec![field]),
+                    Err(_) => ApiErrorDetails::Unknown(value),
+                }
+            }
+            serde_json::Value::Array(_) => match Vec::<FieldError>::deserialize(&value) {
+                Ok(fields) => ApiErrorDetails::FieldErrors(fields),
+                Err(_) => ApiErrorDetails::Unknown(value),
+            },
+            _ => ApiErrorDetails::Unknown(value),
+        };
+
+        Ok(details)
+    }
+}
+
+/// Reference to a Zoho user embedded in another object, such as a record owner.
+#[derive(Debug, Deserialize)]
+pub struct UserRef {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub name: String,
+}
+
+/// Attachment metadata returned by the `get_attachments()` method.
+#[derive(Debug, Deserialize)]
+pub struct Attachment {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+
+    #[serde(alias = "File_Name")]
+    pub file_name: String,
+
+    /// Size of the file in bytes, which Zoho sends as a string.
+    #[serde(alias = "Size")]
+    pub size: String,
+
+    #[serde(alias = "Created_Time")]
+    pub created_time: String,
+
+    #[serde(alias = "Owner")]
+    pub owner: UserRef,
+
+    #[serde(alias = "Created_By")]
+    pub created_by: Option<UserRef>,
+
+    /// URL of the link, for attachments that are links rather than uploaded files.
+    #[serde(alias = "$link_url")]
+    pub link_url: Option<String>,
+}
+
+/// Response returned by the `add_tags()` and `remove_tags()` methods.
+#[derive(Debug, Deserialize)]
+pub struct TagsResponse {
+    pub data: Vec<TagsResponseItem>,
+}
+
+/// Result of a tag action for a single record.
+#[derive(Debug, Deserialize)]
+pub struct TagsResponseItem {
+    pub code: String,
+    pub details: TagsResponseDetails,
+    pub message: String,
+    pub status: String,
+}
+
+/// The record a tag action applied to, and the tags it has afterwards.
+#[derive(Debug, Deserialize)]
+pub struct TagsResponseDetails {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// IDs of the records created or linked by the `convert_lead()` method.
+#[derive(Debug, Deserialize)]
+pub struct ConvertLeadResponse {
+    #[serde(
+        alias = "Accounts",
+        default,
+        deserialize_with = "deserialize_optional_zoho_id"
+    )]
+    pub accounts: Option<String>,
+
+    #[serde(
+        alias = "Contacts",
+        default,
+        deserialize_with = "deserialize_optional_zoho_id"
+    )]
+    pub contacts: Option<String>,
+
+    #[serde(
+        alias = "Deals",
+        default,
+        deserialize_with = "deserialize_optional_zoho_id"
+    )]
+    pub deals: Option<String>,
+}
+
+/// A Zoho CRM user.
+#[derive(Debug, Deserialize)]
+pub struct ZohoUser {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub full_name: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub email: Option<String>,
+    pub status: Option<String>,
+    pub role: Option<ZohoRole>,
+    pub profile: Option<ZohoProfile>,
+    pub time_zone: Option<String>,
+
+    /// Whether the user accepted the invitation to the organization.
+    pub confirm: Option<bool>,
+}
+
+/// Users of the organization, returned by the `get_users()` method.
+pub type UsersResponse = ApiGetManyResponse<ZohoUser>;
+
+/// A role of the organization, returned by the `get_roles()` method.
+#[derive(Debug, Deserialize)]
+pub struct ZohoRole {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub name: String,
+}
+
+/// A profile of the organization, returned by the `get_profiles()` method.
+#[derive(Debug, Deserialize)]
+pub struct ZohoProfile {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub name: String,
+}
+
+/// A CRM module, returned by the `get_modules()` and `get_module()` methods.
+///
+/// The related lists, layouts and profiles are only sent by `get_module()`.
+#[derive(Debug, Deserialize)]
+pub struct ZohoModule {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub module_name: String,
+    pub api_name: String,
+    pub plural_label: String,
+    pub singular_label: String,
+    #[serde(alias = "creatable", default)]
+    pub is_creatable: bool,
+    #[serde(alias = "editable", default)]
+    pub is_editable: bool,
+    #[serde(alias = "deletable", default)]
+    pub is_deletable: bool,
+    pub related_lists: Option<Vec<ModuleRelatedList>>,
+
+    /// Layouts of the module, with their ID and name only.
+    pub layouts: Option<Vec<ZohoLayout>>,
+
+    /// Profiles allowed to access the module.
+    pub profiles: Option<Vec<ZohoProfile>>,
+}
+
+/// A related list of a module, as described by the `get_module()` method.
+#[derive(Debug, Deserialize)]
+pub struct ModuleRelatedList {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub api_name: String,
+    pub display_label: String,
+
+    /// API name of the module the related records belong to, for lists of records.
+    #[serde(default, deserialize_with = "ok_or_none")]
+    pub module: Option<String>,
+
+    pub sequence_number: Option<String>,
+
+    /// Kind of list, such as `default` or `custom_lookup`.
+    #[serde(rename = "type")]
+    pub list_type: Option<String>,
+}
+
+/// A field definition of a module, returned by the `get_fields()` method.
+#[derive(Debug, Deserialize)]
+pub struct ZohoField {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub field_label: String,
+    pub api_name: String,
+    pub data_type: FieldDataType,
+    #[serde(alias = "length")]
+    pub max_length: Option<usize>,
+    /// Whether Zoho requires the field in every layout.
+    #[serde(rename = "system_mandatory", default)]
+    pub required: bool,
+
+    /// Whether the layout makes the field mandatory. Only sent by the `get_layout()` method.
+    #[serde(rename = "required")]
+    pub layout_required: Option<bool>,
+
+    /// Value the field is filled with when a record is created, such as the default picklist
+    /// value of the layout. Only sent by the `get_layout()` method.
+    pub default_value: Option<serde_json::Value>,
+    #[serde(default)]
+    pub read_only: bool,
+    #[serde(alias = "pick_list_values")]
+    pub picklist_values: Option<Vec<PicklistValue>>,
+
+    /// Number of decimal places of a decimal or currency field.
+    pub decimal_place: Option<u32>,
+
+    /// Module a lookup field points to. `None` for other fields, which Zoho sends with an empty
+    /// `lookup` object.
+    #[serde(default, deserialize_with = "ok_or_none")]
+    pub lookup: Option<FieldLookup>,
+}
+
+/// Data type of a field, as described by the `get_fields()` method.
+///
+/// Types this crate does not know yet are kept in `Unknown`, so new Zoho types do not break
+/// deserialization.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FieldDataType {
+    Text,
+    Textarea,
+    Email,
+    Phone,
+    Website,
+    Picklist,
+    MultiSelectPicklist,
+    Integer,
+    BigInt,
+    Double,
+    Currency,
+    Boolean,
+    Date,
+    DateTime,
+    Lookup,
+    OwnerLookup,
+    UserLookup,
+    MultiSelectLookup,
+    MultiUserLookup,
+    AutoNumber,
+    Formula,
+    Subform,
+    FileUpload,
+    ImageUpload,
+    ProfileImage,
+    Unknown(String),
+}
+
+impl FieldDataType {
+    /// Value of the type as sent by Zoho.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FieldDataType::Text => "text",
+            FieldDataType::Textarea => "textarea",
+            FieldDataType::Email => "email",
+            FieldDataType::Phone => "phone",
+            FieldDataType::Website => "website",
+            FieldDataType::Picklist => "picklist",
+            FieldDataType::MultiSelectPicklist => "multiselectpicklist",
+            FieldDataType::Integer => "integer",
+            FieldDataType::BigInt => "bigint",
+            FieldDataType::Double => "double",
+            FieldDataType::Currency => "currency",
+            FieldDataType::Boolean => "boolean",
+            FieldDataType::Date => "date",
+            FieldDataType::DateTime => "datetime",
+            FieldDataType::Lookup => "lookup",
+            FieldDataType::OwnerLookup => "ownerlookup",
+            FieldDataType::UserLookup => "userlookup",
+            FieldDataType::MultiSelectLookup => "multiselectlookup",
+            FieldDataType::MultiUserLookup => "multiuserlookup",
+            FieldDataType::AutoNumber => "autonumber",
+            FieldDataType::Formula => "formula",
+            FieldDataType::Subform => "subform",
+            FieldDataType::FileUpload => "fileupload",
+            FieldDataType::ImageUpload => "imageupload",
+            FieldDataType::ProfileImage => "profileimage",
+            FieldDataType::Unknown(data_type) => data_type,
+        }
+    }
+
+    /// Whether values of the field are numbers.
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            FieldDataType::Integer
+                | FieldDataType::BigInt
+                | FieldDataType::Double
+                | FieldDataType::Currency
+        )
+    }
+
+    /// Whether values of the field are picked from a list.
+    pub fn is_picklist(&self) -> bool {
+        matches!(
+            self,
+            FieldDataType::Picklist | FieldDataType::MultiSelectPicklist
+        )
+    }
+
+    /// Whether values of the field refer to records or users.
+    pub fn is_lookup(&self) -> bool {
+        matches!(
+            self,
+            FieldDataType::Lookup
+                | FieldDataType::OwnerLookup
+                | FieldDataType::UserLookup
+                | FieldDataType::MultiSelectLookup
+                | FieldDataType::MultiUserLookup
+        )
+    }
+}
+
+impl From<&str> for FieldDataType {
+    fn from(data_type: &str) -> FieldDataType {
+        match data_type {
+            "text" => FieldDataType::Text,
+            "textarea" => FieldDataType::Textarea,
+            "email" => FieldDataType::Email,
+            "phone" => FieldDataType::Phone,
+            "website" => FieldDataType::Website,
+            "picklist" => FieldDataType::Picklist,
+            "multiselectpicklist" => FieldDataType::MultiSelectPicklist,
+            "integer" => FieldDataType::Integer,
+            "bigint" => FieldDataType::BigInt,
+            "double" => FieldDataType::Double,
+            "currency" => FieldDataType::Currency,
+            "boolean" => FieldDataType::Boolean,
+            "date" => FieldDataType::Date,
+            "datetime" => FieldDataType::DateTime,
+            "lookup" => FieldDataType::Lookup,
+            "ownerlookup" => FieldDataType::OwnerLookup,
+            "userlookup" => FieldDataType::UserLookup,
+            "multiselectlookup" => FieldDataType::MultiSelectLookup,
+            "multiuserlookup" => FieldDataType::MultiUserLookup,
+            "autonumber" => FieldDataType::AutoNumber,
+            "formula" => FieldDataType::Formula,
+            "subform" => FieldDataType::Subform,
+            "fileupload" => FieldDataType::FileUpload,
+            "imageupload" => FieldDataType::ImageUpload,
+            "profileimage" => FieldDataType::ProfileImage,
+            data_type => FieldDataType::Unknown(String::from(data_type)),
+        }
+    }
+}
+
+impl fmt::Display for FieldDataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldDataType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data_type = String::deserialize(deserializer)?;
+
+        Ok(FieldDataType::from(data_type.as_str()))
+    }
+}
+
+/// Target of a lookup field.
+#[derive(Debug, Deserialize)]
+pub struct FieldLookup {
+    /// API name of the module the lookup points to.
+    #[serde(deserialize_with = "api_name")]
+    pub module: String,
+
+    #[serde(default, deserialize_with = "deserialize_optional_zoho_id")]
+    pub id: Option<String>,
+    pub api_name: Option<String>,
+    pub display_label: Option<String>,
+}
+
+/// A possible value of a picklist field.
+#[derive(Debug, Deserialize)]
+pub struct PicklistValue {
+    pub display_value: String,
+    pub actual_value: String,
+
+    /// `used` for values that can be picked, `unused` for values that were deactivated.
+    #[serde(rename = "type")]
+    pub value_type: Option<String>,
+}
+
+impl PicklistValue {
+    /// Whether the value can still be picked.
+    pub fn is_active(&self) -> bool {
+        self.value_type.as_deref() != Some("unused")
+    }
+}
+
+/// A layout of a module, returned by the `get_layouts()` and `get_layout()` methods.
+///
+/// The optional fields are only sent by `get_layout()`.
+#[derive(Debug, Deserialize)]
+pub struct ZohoLayout {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub name: String,
+    pub status: Option<i32>,
+    #[serde(default)]
+    pub sections: Vec<LayoutSection>,
+    pub visible: Option<bool>,
+    pub created_time: Option<String>,
+    pub modified_time: Option<String>,
+
+    /// Profiles the layout is available to.
+    pub profiles: Option<Vec<ZohoProfile>>,
+}
+
+/// A section of a layout, with the fields it displays.
+#[derive(Debug, Deserialize)]
+pub struct LayoutSection {
+    pub name: String,
+    #[serde(default)]
+    pub fields: Vec<ZohoField>,
+    pub display_label: Option<String>,
+    pub sequence_number: Option<u32>,
+    pub column_count: Option<u32>,
+}
+
+/// A custom view of a module, returned by the `get_custom_views()` and `get_custom_view()`
+/// methods.
+///
+/// `criteria` and `shared_to` are only sent by `get_custom_view()`.
+#[derive(Debug, Deserialize)]
+pub struct CustomView {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub name: String,
+    pub system_name: Option<String>,
+    pub display_value: String,
+    #[serde(default)]
+    pub default: bool,
+    pub category: Option<String>,
+
+    /// Filter of the view. `None` for views showing every record.
+    #[serde(default, deserialize_with = "ok_or_none")]
+    pub criteria: Option<Criteria>,
+
+    /// Roles, groups and users the view is shared with.
+    pub shared_to: Option<Vec<CustomViewShare>>,
+}
+
+/// Filter of a custom view: a single condition, or a group of criteria joined by an operator.
+///
+/// ```
+/// use zohoxide_crm::response::Criteria;
+///
+/// let criteria: Criteria = serde_json::from_str(
+///     r#"{"group_operator":"and","group":[
+///         {"comparator":"equal","field":{"api_name":"Lead_Status","id":"554023000000002611"},"value":"Contacted"},
+///         {"comparator":"greater_than","field":"Annual_Revenue","value":"1000000"}
+///     ]}"#,
+/// )
+/// .unwrap();
+///
+/// if let Criteria::Group { operator, children } = criteria {
+///     assert_eq!(operator, "and");
+///     assert_eq!(children.len(), 2);
+/// }
+/// ```
+// As with `ResponseDataItemDetails`, the variants are tried from the top.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Criteria {
+    Group {
+        /// `and` or `or`.
+        #[serde(rename = "group_operator")]
+        operator: String,
+
+        #[serde(rename = "group")]
+        children: Vec<Criteria>,
+    },
+    Condition {
+        /// API name of the field.
+        #[serde(deserialize_with = "api_name")]
+        field: String,
+
+        /// Comparison, such as `equal`, `not_equal`, `contains` or `greater_than`.
+        comparator: String,
+
+        #[serde(default)]
+        value: serde_json::Value,
+    },
+}
+
+/// A role, group or user a custom view is shared with.
+#[derive(Debug, Deserialize)]
+pub struct CustomViewShare {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub name: Option<String>,
+
+    /// `roles`, `groups` or `users`.
+    #[serde(rename = "type")]
+    pub share_type: Option<String>,
+}
+
+/// Reference to a record embedded in another object, such as the parent of a note.
+#[derive(Debug, Deserialize)]
+pub struct RecordRef {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub name: Option<String>,
+}
+
+/// A note attached to a record, returned by the `get_notes()` method.
+#[derive(Debug, Deserialize)]
+pub struct Note {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+
+    #[serde(rename = "Note_Title")]
+    pub note_title: Option<String>,
+
+    #[serde(rename = "Note_Content")]
+    pub note_content: Option<String>,
+
+    #[serde(rename = "Created_Time")]
+    pub created_time: Option<String>,
+
+    #[serde(rename = "Created_By")]
+    pub created_by: Option<UserRef>,
+
+    /// API name of the module of the parent record.
+    #[serde(rename = "$se_module")]
+    pub se_module: Option<String>,
+
+    #[serde(rename = "Parent_Id")]
+    pub parent_id: Option<RecordRef>,
+}
+
+/// Audit information of a deleted record, usable with the `get_deleted_records()` method.
+#[derive(Debug, Deserialize)]
+pub struct DeletedRecordInfo {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub display_name: Option<String>,
+    pub deleted_by: Option<UserRef>,
+    pub deleted_time: Option<String>,
+}
+
+/// A scheduled mass update, returned by the `mass_update()` method.
+#[derive(Debug)]
+pub struct MassUpdateResponse {
+    pub job_id: String,
+    pub status: String,
+}
+
+/// Raw item of the response to a mass update request.
+#[derive(Debug, Deserialize)]
+pub(crate) struct MassUpdateResponseItem {
+    pub code: String,
+    pub details: MassUpdateResponseDetails,
+    pub message: String,
+    pub status: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct MassUpdateResponseDetails {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub job_id: String,
+}
+
+/// Progress of a mass update, returned by the `get_mass_update_status()` method.
+#[derive(Debug, Deserialize)]
+pub struct MassUpdateStatus {
+    /// Either `SCHEDULED`, `RUNNING` or `COMPLETED`.
+    #[serde(rename = "Status")]
+    pub status: String,
+
+    #[serde(rename = "Total_Count", default)]
+    pub total_count: usize,
+
+    #[serde(rename = "Updated_Count", default)]
+    pub updated_count: usize,
+
+    #[serde(rename = "Not_Updated_Count", default)]
+    pub not_updated_count: usize,
+
+    #[serde(rename = "Failed_Count", default)]
+    pub failed_count: usize,
+}
+
+/// Blueprint of a record, returned by the `get_blueprint()` method.
+#[derive(Debug, Deserialize)]
+pub struct BlueprintResponse {
+    #[serde(default)]
+    pub transitions: Vec<BlueprintTransition>,
+}
+
+/// A transition of a Blueprint that can be applied to a record.
+#[derive(Debug, Deserialize)]
+pub struct BlueprintTransition {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub name: String,
+
+    /// Value of the Blueprint field once the transition is done.
+    pub next_field_value: String,
+}
+
+/// Raw response of the Blueprint endpoint, which wraps the blueprint in a `blueprint` field.
+#[derive(Debug, Deserialize)]
+pub(crate) struct BlueprintEnvelope {
+    pub blueprint: BlueprintResponse,
+}
+
+/// Approval actions available for a record, returned by the `get_approval()` method.
+#[derive(Debug, Deserialize)]
+pub struct ApprovalStatus {
+    #[serde(default)]
+    pub delegate: bool,
+    #[serde(default)]
+    pub approve: bool,
+    #[serde(default)]
+    pub reject: bool,
+    #[serde(default)]
+    pub resubmit: bool,
+}
+
+/// Item of the response to a creation, holding what was created in its details.
+#[derive(Debug, Deserialize)]
+#[serde(bound = "D: DeserializeOwned")]
+pub(crate) struct CreatedItem<D: DeserializeOwned> {
+    pub code: String,
+
+    /// Error items carry details of their own, such as the field in error, which are dropped.
+    #[serde(default, deserialize_with = "ok_or_none")]
+    pub details: Option<D>,
+    pub message: String,
+    pub status: String,
+}
+
+impl<D: DeserializeOwned> CreatedItem<D> {
+    /// What was created, or the error Zoho reported instead.
+    pub(crate) fn into_details(self) -> Result<D, ClientError> {
+        match (self.status.as_str(), self.details) {
+            ("success", Some(details)) => Ok(details),
+            _ => Err(ClientError::ApiError(ApiErrorResponse {
+                code: self.code,
+                message: self.message,
+                status: self.status,
+                details: ApiErrorDetails::Empty,
+            })),
+        }
+    }
+}
+
+/// A bulk read job, returned by the `bulk_read_create()` and `bulk_read_status()` methods.
+#[derive(Debug, Deserialize)]
+pub struct BulkReadJob {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+
+    /// One of `ADDED`, `QUEUED`, `IN PROGRESS`, `COMPLETED` or `FAILURE`.
+    pub state: String,
+
+    /// Result of the job, once it is completed.
+    pub result: Option<BulkReadResult>,
+}
+
+/// Result of a completed bulk read job.
+#[derive(Debug, Deserialize)]
+pub struct BulkReadResult {
+    pub page: Option<u32>,
+    pub count: Option<usize>,
+    pub download_url: Option<String>,
+    #[serde(default)]
+    pub more_records: bool,
+}
+
+/// Information about a file streamed into a writer by one of the `download_*_to()` methods.
+#[derive(Debug)]
+pub struct DownloadInfo {
+    pub bytes_written: u64,
+
+    /// Original name of the file, from the `Content-Disposition` header.
+    pub file_name: Option<String>,
+
+    pub content_type: Option<String>,
+}
+
+/// A file uploaded for a bulk write job.
+#[derive(Debug, Deserialize)]
+pub(crate) struct UploadedFile {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub file_id: String,
+}
+
+/// A bulk write job, returned by the `bulk_write_create()` and `bulk_write_status()` methods.
+#[derive(Debug, Deserialize)]
+pub struct BulkWriteJob {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+
+    /// One of `ADDED`, `IN PROGRESS`, `COMPLETED` or `FAILED`.
+    #[serde(default = "bulk_write_added")]
+    pub status: String,
+
+    /// Result of the job, once it is completed.
+    pub result: Option<BulkWriteResult>,
+
+    /// Progress of each imported file.
+    #[serde(default)]
+    pub resource: Vec<BulkWriteResource>,
+}
+
+/// Status of a job Zoho has just accepted, which it does not send back on creation.
+fn bulk_write_added() -> String {
+    String::from("ADDED")
+}
+
+/// Result of a completed bulk write job.
+#[derive(Debug, Deserialize)]
+pub struct BulkWriteResult {
+    /// URL of a CSV file detailing the result of every record.
+    pub download_url: Option<String>,
+    #[serde(default)]
+    pub created_count: usize,
+    #[serde(default)]
+    pub updated_count: usize,
+    #[serde(default)]
+    pub failed_count: usize,
+}
+
+/// Progress of a file imported by a bulk write job.
+#[derive(Debug, Deserialize)]
+pub struct BulkWriteResource {
+    pub module: Option<String>,
+    pub status: Option<String>,
+    pub file: Option<BulkWriteFile>,
+}
+
+/// Counts of the records of a file imported by a bulk write job.
+#[derive(Debug, Deserialize)]
+pub struct BulkWriteFile {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub added_count: usize,
+    #[serde(default)]
+    pub skipped_count: usize,
+    #[serde(default)]
+    pub updated_count: usize,
+    #[serde(default)]
+    pub total_count: usize,
+}
+
+/// Response made of a single status, returned by action endpoints such as `upload_photo()`.
+#[derive(Debug, Deserialize)]
+pub struct StatusResponse {
+    pub code: String,
+    pub message: String,
+    pub status: String,
+}
+
+/// A file uploaded to the Zoho File System, returned by the `upload_file()` method.
+#[derive(Debug, Deserialize)]
+pub struct FileUploadResponse {
+    /// Encrypted ID of the file, to set on a file upload field.
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub name: Option<String>,
+}
+
+/// A tag defined for a module, returned by the `get_tags()` method.
+#[derive(Debug, Deserialize)]
+pub struct Tag {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub name: String,
+    pub created_by: Option<UserRef>,
+    pub created_time: Option<String>,
+    pub modified_by: Option<UserRef>,
+    pub modified_time: Option<String>,
+}
+
+/// Response returned by the `create_tags()`, `rename_tag()` and `delete_tag()` methods.
+#[derive(Debug, Deserialize)]
+pub struct TagSettingsResponse {
+    pub tags: Vec<TagSettingsResponseItem>,
+}
+
+/// Result of creating, renaming or deleting a single tag.
+#[derive(Debug, Deserialize)]
+pub struct TagSettingsResponseItem {
+    pub code: String,
+    pub details: TagSettingsDetails,
+    pub message: String,
+    pub status: String,
+}
+
+impl TagSettingsResponseItem {
+    /// ID of the tag this item refers to, when Zoho returned one.
+    pub fn tag_id(&self) -> Option<&str> {
+        match &self.details {
+            TagSettingsDetails::Tag(tag) => Some(&tag.id),
+            TagSettingsDetails::Other(details) => details.id.as_deref(),
+        }
+    }
+}
+
+// As with `ResponseDataItemDetails`, the last variant matches any object, so it must come last.
+/// The tag written, or only its ID (as for deleted tags) or the field in error.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum TagSettingsDetails {
+    Tag(Tag),
+    Other(ResponseDataItemDetailsError),
+}
+
+/// Number of records carrying a tag, returned by the `tag_record_count()` method.
+#[derive(Debug, Deserialize)]
+pub struct TagRecordCount {
+    /// Zoho sends the count as a string.
+    #[serde(deserialize_with = "u64_from_string_or_number")]
+    pub count: u64,
+}
+
+/// Deserialize a count Zoho may send either as a number or as a string.
+pub(crate) fn u64_from_string_or_number<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Count {
+        Number(u64),
+        String(String),
+    }
+
+    match Count::deserialize(deserializer)? {
+        Count::Number(count) => Ok(count),
+        Count::String(count) => count.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+/// Response returned by the `get_emails()` method.
+#[derive(Debug, Default, Deserialize)]
+pub struct EmailsResponse {
+    #[serde(rename = "email_related_list", alias = "Email_entries", default)]
+    pub emails: Vec<Email>,
+
+    #[serde(default)]
+    pub info: EmailsInfo,
+}
+
+/// Pagination of the `get_emails()` method, which pages with indexes rather than page numbers.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct EmailsInfo {
+    pub count: usize,
+    pub more_records: bool,
+    pub per_page: usize,
+
+    /// Value to send as the `index` parameter to fetch the next page.
+    pub next_index: Option<String>,
+    pub prev_index: Option<String>,
+}
+
+/// An email sent or received for a record.
+#[derive(Debug, Deserialize)]
+pub struct Email {
+    pub message_id: String,
+    pub subject: Option<String>,
+    pub from: EmailSender,
+
+    #[serde(default)]
+    pub to: Vec<EmailRecipient>,
+
+    #[serde(default)]
+    pub cc: Vec<EmailRecipient>,
+
+    /// Time the email was sent.
+    #[serde(alias = "sent_time")]
+    pub time: Option<String>,
+
+    #[serde(default)]
+    pub sent: bool,
+
+    #[serde(default)]
+    pub read: bool,
+
+    pub owner: Option<UserRef>,
+    pub summary: Option<String>,
+}
+
+/// Sender of an email.
+#[derive(Debug, Deserialize)]
+pub struct EmailSender {
+    pub user_name: Option<String>,
+    pub email: String,
+}
+
+/// Recipient of an email.
+#[derive(Debug, Deserialize)]
+pub struct EmailRecipient {
+    pub user_name: Option<String>,
+    pub email: String,
+}
+
+/// A notification channel, returned by the `get_webhooks()` method.
+#[derive(Debug, Deserialize)]
+pub struct Webhook {
+    #[serde(deserialize_with = "u64_from_string_or_number")]
+    pub channel_id: u64,
+
+    #[serde(default)]
+    pub events: Vec<String>,
+
+    pub notify_url: String,
+    pub token: Option<String>,
+    pub channel_expiry: Option<String>,
+
+    /// Module the channel notifies about.
+    pub resource_name: Option<String>,
+
+    #[serde(default)]
+    pub notify_on_related_action: bool,
+}
+
+impl Webhook {
+    /// Time at which the channel expires, to schedule its renewal with `update_webhook()`.
+    ///
+    /// `None` when Zoho did not send an expiry, or sent one not in ISO 8601 format.
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        parse_date_time(self.channel_expiry.as_deref()?)
+    }
+
+    /// Time left before the channel expires, zero once it has expired.
+    ///
+    /// `None` when the expiry is unknown, as for `expires_at()`.
+    pub fn expires_in(&self) -> Option<Duration> {
+        expiry_in(self.channel_expiry.as_deref())
+    }
+}
+
+/// Response returned by the `create_webhook()`, `update_webhook()` and `delete_webhook()`
+/// methods.
+#[derive(Debug, Deserialize)]
+pub struct WebhookResponse {
+    pub watch: Vec<WebhookResponseItem>,
+}
+
+/// Result of a webhook action for a single channel.
+#[derive(Debug, Deserialize)]
+pub struct WebhookResponseItem {
+    pub code: String,
+    pub details: WebhookResponseDetails,
+    pub message: String,
+    pub status: String,
+}
+
+/// Channels a webhook action applied to.
+///
+/// Creating or updating a channel lists one entry per module in `events`, while disabling it
+/// describes the channel directly.
+#[derive(Debug, Deserialize)]
+pub struct WebhookResponseDetails {
+    #[serde(default)]
+    pub events: Vec<WebhookChannel>,
+
+    #[serde(flatten)]
+    pub channel: Option<WebhookChannel>,
+}
+
+/// A notification channel for a single module.
+#[derive(Debug, Deserialize)]
+pub struct WebhookChannel {
+    #[serde(deserialize_with = "u64_from_string_or_number")]
+    pub channel_id: u64,
+
+    pub channel_expiry: Option<String>,
+    pub resource_name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_zoho_id")]
+    pub resource_id: Option<String>,
+    pub resource_uri: Option<String>,
+}
+
+impl WebhookChannel {
+    /// Time at which the channel expires, as for
+    /// [`Webhook::expires_at()`](struct.Webhook.html#method.expires_at).
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        parse_date_time(self.channel_expiry.as_deref()?)
+    }
+
+    /// Time left before the channel expires, as for
+    /// [`Webhook::expires_in()`](struct.Webhook.html#method.expires_in).
+    pub fn expires_in(&self) -> Option<Duration> {
+        expiry_in(self.channel_expiry.as_deref())
+    }
+}
+
+/// Result of the `send_mail()` method.
+#[derive(Debug, Deserialize)]
+pub struct SendMailResponse {
+    /// ID of the email, as listed by the `get_emails()` method.
+    pub message_id: String,
+}
+
+/// Deserialize a value, falling back to `None` when it does not have the expected shape.
+fn ok_or_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+
+    Ok(T::deserialize(value).ok())
+}
+
+/// Users a record is shared with, returned by the `get_record_shares()` method.
+#[derive(Debug, Default, Deserialize)]
+pub struct RecordSharesResponse {
+    #[serde(default)]
+    pub share: Vec<RecordShare>,
+}
+
+/// A user a record is shared with.
+#[derive(Debug, Deserialize)]
+pub struct RecordShare {
+    pub user: UserRef,
+
+    /// One of `read_only`, `read_write` or `full_access`.
+    pub permission: String,
+
+    #[serde(default)]
+    pub share_related_records: bool,
+
+    pub shared_by: Option<UserRef>,
+    pub shared_time: Option<String>,
+}
+
+/// Response returned by the `share_record()` and `update_record_shares()` methods.
+#[derive(Debug, Deserialize)]
+pub struct ShareResponse {
+    pub share: Vec<ShareResponseItem>,
+}
+
+/// Result of sharing a record with a single user, in the order of the input.
+#[derive(Debug, Deserialize)]
+pub struct ShareResponseItem {
+    pub code: String,
+
+    /// Field in error, for failed items.
+    pub details: Option<ResponseDataItemDetailsError>,
+
+    pub message: String,
+    pub status: String,
+}
+
+/// An email template, returned by the `get_email_templates()` method.
+#[derive(Debug, Deserialize)]
+pub struct EmailTemplate {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub name: String,
+    pub subject: Option<String>,
+
+    /// Sender set on the template, when it has one.
+    pub from_address: Option<String>,
+}
+
+/// A change made to a record, returned by the `get_record_timeline()` method.
+#[derive(Debug, Deserialize)]
+pub struct TimelineEntry {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+
+    /// Kind of change, such as `added`, `updated` or `deleted`.
+    pub action: String,
+
+    pub done_by: Option<UserRef>,
+    pub audited_time: String,
+
+    /// Where the change came from, such as `crm_ui` or `crm_api`.
+    pub source: String,
+}
+
+/// A territory of the organization, returned by the `get_territories()` method.
+#[derive(Debug, Deserialize)]
+pub struct Territory {
+    #[serde(deserialize_with = "deserialize_zoho_id")]
+    pub id: String,
+    pub name: String,
+    pub manager: Option<UserRef>,
+
+    /// ID of the parent territory, `None` for the root territory.
+    #[serde(default, deserialize_with = "deserialize_optional_zoho_id")]
+    pub parent_id: Option<String>,
+
+    pub forecast_manager: Option<UserRef>,
+}
+
+/// Response to a composite request, returned by the `composite()` method.
+#[derive(Debug, Deserialize)]
+pub struct CompositeResponse {
+    /// Results of the sub-requests, in the order they were added.
+    #[serde(rename = "__composite_requests")]
+    pub results: Vec<CompositeResult>,
+}
+
+/// Result of a single sub-request of a composite request.
+///
+/// A failed sub-request does not fail the whole call: its `status` is `error`, and its
+/// `code` and `message` describe the failure.
+#[derive(Debug, Deserialize)]
+pub struct CompositeResult {
+    pub sub_request_id: String,
+    pub code: String,
+    pub message: String,
+    pub status: String,
+    pub details: Option<CompositeResultDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CompositeResultDetails {
+    /// Response the API function gave to the sub-request, absent when it was not run.
+    pub response: Option<CompositeSubResponse>,
+}
+
+/// Response of the API function called by a sub-request.
+#[derive(Debug, Deserialize)]
+pub struct CompositeSubResponse {
+    pub status_code: u16,
+    #[serde(default)]
+    pub body: serde_json::Value,
+}
+
+impl CompositeResult {
+    /// Whether the sub-request succeeded.
+    pub fn is_success(&self) -> bool {
+        self.status == "success"
+    }
+
+    /// HTTP status the API function answered the sub-request with.
+    pub fn status_code(&self) -> Option<u16> {
+        self.response().map(|response| response.status_code)
+    }
+
+    /// JSON body the API function answered the sub-request with.
+    pub fn body(&self) -> Option<&serde_json::Value> {
+        self.response().map(|response| &response.body)
+    }
+
+    fn response(&self) -> Option<&CompositeSubResponse> {
+        self.details.as_ref()?.response.as_ref()
+    }
+}
+
+/// Time left before a channel expiry, zero once it has passed.
+fn expiry_in(channel_expiry: Option<&str>) -> Option<Duration> {
+    let expires_at = parse_date_time(channel_expiry?)?;
+
+    Some(
+        expires_at
+            .duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Parse a date and time in the ISO 8601 format Zoho uses, such as `2023-05-02T11:00:00+02:00`.
+#[cfg(feature = "chrono")]
+fn parse_date_time(value: &str) -> Option<SystemTime> {
+    let date_time: crate::dates::ZohoDateTime = value.parse().ok()?;
+
+    Some(SystemTime::from(date_time.0))
+}
+
+/// Parse a date and time in the ISO 8601 format Zoho uses, such as `2023-05-02T11:00:00+02:00`.
+///
+/// The seconds may have a fraction. A UTC offset, or `Z`, is required.
+#[cfg(not(feature = "chrono"))]
+fn parse_date_time(value: &str) -> Option<SystemTime> {
+    let (date, time) = value.split_once('T')?;
+    let (time, offset_secs) = match time.strip_suffix('Z') {
+        Some(time) => (time, 0),
+        None => {
+            let split = time.rfind(['+', '-'])?;
+            let (time, offset) = time.split_at(split);
+            let (hours, minutes) = offset[1..].split_once(':')?;
+            let offset_secs = hours.parse::<i64>().ok()? * 3600 + minutes.parse::<i64>().ok()? * 60;
+
+            if offset.starts_with('-') {
+                (time, -offset_secs)
+            } else {
+                (time, offset_secs)
+            }
+        }
+    };
+
+    let mut date = date.splitn(3, '-').map(|part| part.parse::<i64>().ok());
+    let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+    let (time, nanos) = match time.split_once('.') {
+        Some((time, fraction)) => (time, parse_fraction(fraction)?),
+        None => (time, 0),
+    };
+    let mut time = time.splitn(3, ':').map(|part| part.parse::<i64>().ok());
+    let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || !(0..=23).contains(&hour)
+        || !(0..=59).contains(&minute)
+        || !(0..=59).contains(&second)
+    {
+        return None;
+    }
+
+    // Days since the Unix epoch of a date in the proleptic Gregorian calendar.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    let timestamp = days * 86400 + hour * 3600 + minute * 60 + second - offset_secs;
+
+    UNIX_EPOCH.checked_add(Duration::new(u64::try_from(timestamp).ok()?, nanos))
+}
+
+/// Number of days in `month` (from 1 to 12) of `year`.
+#[cfg(not(feature = "chrono"))]
+fn days_in_month(year: i64, month: i64) -> i64 {
+    let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+
+    match month {
+        2 if is_leap_year => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Nanoseconds of the fractional part of a number of seconds, given without its dot.
+#[cfg(not(feature = "chrono"))]
+fn parse_fraction(fraction: &str) -> Option<u32> {
+    if fraction.is_empty() || fraction.len() > 9 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let digits: u32 = fraction.parse().ok()?;
+
+    Some(digits * 10u32.pow(9 - fraction.len() as u32))
+}
+
+/// Deserialize the API name of a module or field, which Zoho sends either as a string or as an
+/// object with an `api_name`.
+fn api_name<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ApiName {
+        Name(String),
+        Object { api_name: String },
+    }
+
+    match ApiName::deserialize(deserializer)? {
+        ApiName::Name(api_name) | ApiName::Object { api_name } => Ok(api_name),
+    }
+}
+
+/// Tests of the date parser used without the `chrono` feature, which relies on `ZohoDateTime`
+/// otherwise.
+#[cfg(all(test, not(feature = "chrono")))]
+mod tests {
+    use super::*;
+
+    /// Time `secs` seconds and `nanos` nanoseconds after the Unix epoch.
+    fn at(secs: u64, nanos: u32) -> Option<SystemTime> {
+        Some(UNIX_EPOCH + Duration::new(secs, nanos))
+    }
+
+    #[test]
+    /// Tests that a UTC date and time is read as is.
+    fn parse_date_time_utc() {
+        assert_eq!(parse_date_time("2023-05-02T09:00:00Z"), at(1683018000, 0));
+        assert_eq!(parse_date_time("1970-01-01T00:00:00Z"), at(0, 0));
+    }
+
+    #[test]
+    /// Tests that positive and negative offsets, including half hours, are subtracted.
+    fn parse_date_time_offsets() {
+        assert_eq!(
+            parse_date_time("2023-05-02T04:00:00-05:00"),
+            at(1683018000, 0)
+        );
+        assert_eq!(
+            parse_date_time("2023-05-02T14:30:00+05:30"),
+            at(1683018000, 0)
+        );
+        assert_eq!(
+            parse_date_time("2023-05-01T23:00:00-10:00"),
+            at(1683018000, 0)
+        );
+    }
+
+    #[test]
+    /// Tests that February 29th is only accepted on leap years.
+    fn parse_date_time_leap_day() {
+        assert_eq!(parse_date_time("2024-02-29T00:00:00Z"), at(1709164800, 0));
+        assert_eq!(parse_date_time("2000-02-29T00:00:00Z"), at(951782400, 0));
+        assert_eq!(parse_date_time("2023-02-29T00:00:00Z"), None);
+        assert_eq!(parse_date_time("2100-02-29T00:00:00Z"), None);
+    }
+
+    #[test]
+    /// Tests that fractional seconds of up to nine digits are kept.
+    fn parse_date_time_fraction() {
+        assert_eq!(
+            parse_date_time("2023-05-02T09:00:00.25Z"),
+            at(1683018000, 250_000_000)
+        );
+        assert_eq!(
+            parse_date_time("2023-05-02T09:00:00.000000001+00:00"),
+            at(1683018000, 1)
+        );
+        assert_eq!(parse_date_time("2023-05-02T09:00:00.Z"), None);
+        assert_eq!(parse_date_time("2023-05-02T09:00:00.0000000001Z"), None);
+    }
+
+    #[test]
+    /// Tests that a date and time without an offset is rejected.
+    fn parse_date_time_requires_offset() {
+        assert_eq!(parse_date_time("2023-05-02T09:00:00"), None);
+        assert_eq!(parse_date_time("2023-05-02"), None);
+    }
+
+    #[test]
+    /// Tests that days, hours, minutes and seconds out of range are rejected.
+    fn parse_date_time_out_of_range() {
+        assert_eq!(parse_date_time("2023-04-31T00:00:00Z"), None);
+        assert_eq!(parse_date_time("2023-01-00T00:00:00Z"), None);
+        assert_eq!(parse_date_time("2023-13-01T00:00:00Z"), None);
+        assert_eq!(parse_date_time("2023-05-02T24:00:00Z"), None);
+        assert_eq!(parse_date_time("2023-05-02T09:60:00Z"), None);
+        assert_eq!(parse_date_time("2023-05-02T09:00:60Z"), None);
+    }
+
+    #[test]
+    /// Tests that a date before the Unix epoch returns `None`.
+    fn parse_date_time_before_epoch() {
+        assert_eq!(parse_date_time("1969-12-31T23:59:59Z"), None);
+        assert_eq!(parse_date_time("1970-01-01T01:00:00+02:00"), None);
+    }
+}
@@ -0,0 +1,205 @@
+//! Functions to use with `#[serde(deserialize_with)]` and `#[serde(serialize_with)]` for
+//! values Zoho does not always send with the expected JSON type.
+//!
+//! ### Booleans
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use zohoxide_crm::serde_helpers::{deserialize_zoho_bool, serialize_zoho_bool};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Lead {
+//!     #[serde(
+//!         rename = "Email_Opt_Out",
+//!         deserialize_with = "deserialize_zoho_bool",
+//!         serialize_with = "serialize_zoho_bool"
+//!     )]
+//!     email_opt_out: bool,
+//! }
+//!
+//! let lead: Lead = serde_json::from_str(r#"{"Email_Opt_Out":"true"}"#).unwrap();
+//! assert!(lead.email_opt_out);
+//!
+//! let lead: Lead = serde_json::from_str(r#"{"Email_Opt_Out":false}"#).unwrap();
+//! assert!(!lead.email_opt_out);
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&lead).unwrap(),
+//!     r#"{"Email_Opt_Out":"false"}"#
+//! );
+//! ```
+//!
+//! ### IDs
+//!
+//! Depending on the endpoint and the API version, Zoho sends IDs as JSON strings or numbers.
+//! The ID fields of the `response` types use these functions, so they accept both.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use zohoxide_crm::serde_helpers::{deserialize_zoho_id, serialize_zoho_id};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Account {
+//!     #[serde(deserialize_with = "deserialize_zoho_id", serialize_with = "serialize_zoho_id")]
+//!     id: String,
+//! }
+//!
+//! let account: Account = serde_json::from_str(r#"{"id":554023000000634001}"#).unwrap();
+//! assert_eq!(account.id, "554023000000634001");
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&account).unwrap(),
+//!     r#"{"id":"554023000000634001"}"#
+//! );
+//! ```
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ZohoBool {
+    Bool(bool),
+    String(String),
+}
+
+/// Deserialize a boolean sent either as a JSON boolean or as a `"true"` or `"false"` string.
+pub fn deserialize_zoho_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match ZohoBool::deserialize(deserializer)? {
+        ZohoBool::Bool(value) => Ok(value),
+        ZohoBool::String(value) if value == "true" => Ok(true),
+        ZohoBool::String(value) if value == "false" => Ok(false),
+        ZohoBool::String(value) => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Str(&value),
+            &"a boolean or a \"true\" or \"false\" string",
+        )),
+    }
+}
+
+/// Serialize a boolean as a `"true"` or `"false"` string, the way
+/// [`deserialize_zoho_bool`](fn.deserialize_zoho_bool.html) reads it.
+pub fn serialize_zoho_bool<S>(value: &bool, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(if *value { "true" } else { "false" })
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ZohoId {
+    String(String),
+    Number(serde_json::Number),
+}
+
+impl From<ZohoId> for String {
+    fn from(id: ZohoId) -> String {
+        match id {
+            ZohoId::String(id) => id,
+            ZohoId::Number(id) => id.to_string(),
+        }
+    }
+}
+
+/// Deserialize an ID sent either as a JSON string or as a JSON number into a `String`.
+pub fn deserialize_zoho_id<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    ZohoId::deserialize(deserializer).map(String::from)
+}
+
+/// Same as [`deserialize_zoho_id`](fn.deserialize_zoho_id.html), for IDs that may be `null`.
+///
+/// Use it with `#[serde(default)]` so a missing ID gives `None`.
+pub fn deserialize_optional_zoho_id<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<ZohoId>::deserialize(deserializer).map(|id| id.map(String::from))
+}
+
+/// Serialize an ID as a JSON string, the form every Zoho endpoint accepts.
+pub fn serialize_zoho_id<S>(id: &str, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Flag {
+        #[serde(
+            deserialize_with = "deserialize_zoho_bool",
+            serialize_with = "serialize_zoho_bool"
+        )]
+        value: bool,
+    }
+
+    #[test]
+    /// Tests that a boolean is read from a `"true"`/`"false"` string or a JSON boolean.
+    fn zoho_bool_accepts_strings_and_booleans() {
+        for (json, expected) in [
+            (r#"{"value":"true"}"#, true),
+            (r#"{"value":"false"}"#, false),
+            (r#"{"value":true}"#, true),
+            (r#"{"value":false}"#, false),
+        ] {
+            let flag: Flag = serde_json::from_str(json).unwrap();
+            assert_eq!(flag.value, expected);
+        }
+
+        assert!(serde_json::from_str::<Flag>(r#"{"value":"yes"}"#).is_err());
+        assert!(serde_json::from_str::<Flag>(r#"{"value":1}"#).is_err());
+    }
+
+    #[test]
+    /// Tests that a boolean is written as the string Zoho sends.
+    fn zoho_bool_round_trip() {
+        let json = serde_json::to_string(&Flag { value: true }).unwrap();
+
+        assert_eq!(json, r#"{"value":"true"}"#);
+        assert!(serde_json::from_str::<Flag>(&json).unwrap().value);
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Ids {
+        #[serde(
+            deserialize_with = "deserialize_zoho_id",
+            serialize_with = "serialize_zoho_id"
+        )]
+        id: String,
+        #[serde(default, deserialize_with = "deserialize_optional_zoho_id")]
+        parent_id: Option<String>,
+    }
+
+    #[test]
+    /// Tests that an ID is read from a string or a number, and an optional ID from `null` too.
+    fn zoho_id_accepts_strings_and_numbers() {
+        let ids: Ids = serde_json::from_str(r#"{"id":"554023000000634001"}"#).unwrap();
+        assert_eq!(ids.id, "554023000000634001");
+        assert_eq!(ids.parent_id, None);
+
+        let ids: Ids =
+            serde_json::from_str(r#"{"id":5540230000006340011,"parent_id":554023000000634001}"#)
+                .unwrap();
+        assert_eq!(ids.id, "5540230000006340011");
+        assert_eq!(ids.parent_id.as_deref(), Some("554023000000634001"));
+
+        let ids: Ids = serde_json::from_str(r#"{"id":"1","parent_id":null}"#).unwrap();
+        assert_eq!(ids.parent_id, None);
+
+        assert!(serde_json::from_str::<Ids>(r#"{"id":true}"#).is_err());
+        assert_eq!(
+            serde_json::to_string(&ids).unwrap(),
+            r#"{"id":"1","parent_id":null}"#
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use crate::client::Client;
+use crate::client_error::ClientError;
+use crate::modules::AsModuleName;
+use crate::record_id::AsRecordId;
+use crate::response;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Handle to a [`Client`](struct.Client.html) that can be cloned and shared between threads.
+///
+/// `Client` methods take `&mut self`, so sharing a client in the state of a web application
+/// means wrapping it in `Arc<Mutex<Client>>` and locking it around every call. `SharedClient`
+/// does that locking. Clones share the same client, and so the same access token.
+///
+/// [`with`](struct.SharedClient.html#method.with) is the way to call any `Client` method: it
+/// holds the lock while the closure runs, so several calls can also be made under a single
+/// lock. Only the most common record methods are also available directly, as shortcuts.
+///
+/// When another thread panicked while holding the lock, the methods returning a `Result` give a
+/// [`ClientError`](enum.ClientError.html) instead of panicking.
+///
+/// ### Example
+///
+/// ```no_run
+/// use zohoxide_crm::{Client, SharedClient};
+///
+/// let client = SharedClient::new(Client::from_env().unwrap());
+///
+/// let handle = {
+///     let client = client.clone();
+///     std::thread::spawn(move || client.get::<serde_json::Value>("Accounts", "ZOHO_ID_HERE"))
+/// };
+///
+/// let contacts = client.get_many::<serde_json::Value>("Contacts", None).unwrap();
+/// let account = handle.join().unwrap().unwrap();
+/// let layouts = client.with(|client| client.get_layouts("Accounts")).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct SharedClient {
+    client: Arc<Mutex<Client>>,
+}
+
+impl SharedClient {
+    /// Wrap `client` so it can be cloned and shared between threads.
+    pub fn new(client: Client) -> SharedClient {
+        SharedClient {
+            client: Arc::new(Mutex::new(client)),
+        }
+    }
+
+    /// Run `f` with the client, holding the lock until it returns.
+    pub fn with<R>(
+        &self,
+        f: impl FnOnce(&mut Client) -> Result<R, ClientError>,
+    ) -> Result<R, ClientError> {
+        f(&mut *self.lock()?)
+    }
+
+    /// Get the access token.
+    ///
+    /// The token is still read when another thread panicked while holding the lock.
+    pub fn access_token(&self) -> Option<String> {
+        self.client
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .access_token()
+    }
+
+    /// Fetch a new access token, shared by all the clones of this handle.
+    ///
+    /// See [`Client::get_new_token`](struct.Client.html#method.get_new_token).
+    pub fn get_new_token(&self) -> Result<crate::TokenRecord, ClientError> {
+        self.lock()?.get_new_token()
+    }
+
+    /// Fetch a single record.
+    ///
+    /// See [`Client::get`](struct.Client.html#method.get).
+    pub fn get<T: serde::de::DeserializeOwned>(
+        &self,
+        module: impl AsModuleName,
+        id: impl AsRecordId,
+    ) -> Result<response::ApiGetResponse<T>, ClientError> {
+        self.lock()?.get(module, id)
+    }
+
+    /// Fetch a page of records.
+    ///
+    /// See [`Client::get_many`](struct.Client.html#method.get_many).
+    pub fn get_many<T: serde::de::DeserializeOwned>(
+        &self,
+        module: impl AsModuleName,
+        params: Option<String>,
+    ) -> Result<response::ApiGetManyResponse<T>, ClientError> {
+        self.lock()?.get_many(module, params)
+    }
+
+    /// Fetch the records matching a COQL query.
+    ///
+    /// See [`Client::coql`](struct.Client.html#method.coql).
+    pub fn coql<T: serde::de::DeserializeOwned>(
+        &self,
+        query: &str,
+    ) -> Result<response::ApiGetManyResponse<T>, ClientError> {
+        self.lock()?.coql(query)
+    }
+
+    /// Insert records.
+    ///
+    /// See [`Client::insert`](struct.Client.html#method.insert).
+    pub fn insert<T: serde::ser::Serialize>(
+        &self,
+        module: impl AsModuleName,
+        data: Vec<T>,
+    ) -> Result<response::ApiSuccessResponse, ClientError> {
+        self.lock()?.insert(module, data)
+    }
+
+    /// Update records.
+    ///
+    /// See [`Client::update_many`](struct.Client.html#method.update_many).
+    pub fn update_many<T: serde::ser::Serialize>(
+        &self,
+        module: impl AsModuleName,
+        data: Vec<T>,
+    ) -> Result<response::ApiSuccessResponse, ClientError> {
+        self.lock()?.update_many(module, data)
+    }
+
+    /// Insert or update records, matching them on an external ID field.
+    ///
+    /// See [`Client::upsert_by_external_id`](struct.Client.html#method.upsert_by_external_id).
+    pub fn upsert_by_external_id<T: serde::ser::Serialize>(
+        &self,
+        module: impl AsModuleName,
+        external_field: &str,
+        data: Vec<T>,
+    ) -> Result<response::ApiSuccessResponse, ClientError> {
+        self.lock()?
+            .upsert_by_external_id(module, external_field, data)
+    }
+
+    /// Wait for the client lock, failing when another thread panicked while holding it.
+    fn lock(&self) -> Result<MutexGuard<'_, Client>, ClientError> {
+        self.client
+            .lock()
+            .map_err(|_| ClientError::from("Client lock was poisoned"))
+    }
+}
+
+impl From<Client> for SharedClient {
+    fn from(client: Client) -> SharedClient {
+        SharedClient::new(client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_shared_client(api_domain: String) -> SharedClient {
+        Client::builder()
+            .client_id("")
+            .client_secret("")
+            .refresh_token("")
+            .access_token(Some(String::from("access_token")))
+            .api_domain(Some(api_domain))
+            .build()
+            .into()
+    }
+
+    #[test]
+    /// Tests that clones of a `SharedClient` send their requests with the same client.
+    fn clones_share_the_client() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/crm/v2/Accounts/40000000123456789")
+            .with_status(200)
+            .with_body(r#"{"data":[{"id":"40000000123456789"}]}"#)
+            .expect(2)
+            .create();
+
+        let client = get_shared_client(server.url());
+        let clone = client.clone();
+
+        let handle = std::thread::spawn(move || {
+            clone
+                .get::<serde_json::Value>("Accounts", "40000000123456789")
+                .unwrap()
+        });
+        client
+            .get::<serde_json::Value>("Accounts", "40000000123456789")
+            .unwrap();
+        handle.join().unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    /// Tests that `get()` returns an error instead of panicking when the lock is poisoned.
+    fn get_reports_poisoned_lock() {
+        let client = get_shared_client(String::from("http://localhost"));
+        let clone = client.clone();
+
+        let _ = std::thread::spawn(move || {
+            let _guard = clone.client.lock().unwrap();
+            panic!("poison the lock");
+        })
+        .join();
+
+        assert!(client.access_token().is_some());
+        match client.get::<serde_json::Value>("Accounts", "40000000123456789") {
+            Err(ClientError::General(error)) => {
+                assert_eq!(error.to_string(), "Client lock was poisoned")
+            }
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+}
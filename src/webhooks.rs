@@ -0,0 +1,184 @@
+//! Types and helpers for the notifications Zoho sends to a webhook channel.
+//!
+//! Zoho does not sign notifications. Instead, it sends back the `token` set on the channel (see
+//! [`WebhookConfig`](../request/struct.WebhookConfig.html)) with every notification, in the
+//! `token` field of the JSON payload.
+//!
+//! ### Example
+//!
+//! ```
+//! use zohoxide_crm::webhooks::{verify_token, verify_webhook_signature, NotificationEvent};
+//!
+//! let payload = br#"{"channel_id":"1000000068001","token":"shared-secret","module":"Leads","operation":"insert","ids":["554023000000527001"]}"#;
+//!
+//! assert!(verify_webhook_signature(payload, "shared-secret", "shared-secret"));
+//! assert!(!verify_webhook_signature(payload, "guess", "shared-secret"));
+//!
+//! let event: NotificationEvent = serde_json::from_slice(payload).unwrap();
+//!
+//! assert!(verify_token(&event, "shared-secret"));
+//! assert_eq!(event.ids, vec!["554023000000527001"]);
+//! ```
+
+use serde::Deserialize;
+
+/// Body of a notification Zoho posts to the `notify_url` of a channel.
+#[derive(Debug, Deserialize)]
+pub struct NotificationEvent {
+    #[serde(deserialize_with = "crate::response::u64_from_string_or_number")]
+    pub channel_id: u64,
+
+    /// API name of the module the records belong to.
+    #[serde(rename = "module", alias = "module_api_name")]
+    pub module_api_name: String,
+
+    /// What happened to the records: `insert`, `update` or `delete`.
+    pub operation: String,
+
+    /// IDs of the records the operation applied to.
+    #[serde(default)]
+    pub ids: Vec<String>,
+
+    /// Token set on the channel, if any.
+    pub token: Option<String>,
+
+    /// Time Zoho sent the notification at, in milliseconds since the Unix epoch.
+    pub server_time: Option<u64>,
+
+    pub resource_uri: Option<String>,
+
+    /// Fields changed by an update, for each record, when the channel asked for them.
+    #[serde(default)]
+    pub affected_fields: Vec<serde_json::Value>,
+}
+
+/// Check the `token` field of a parsed notification against the token set on its channel.
+///
+/// The tokens are compared in constant time. A notification without a token, or an empty
+/// `expected_token`, never matches.
+pub fn verify_token(event: &NotificationEvent, expected_token: &str) -> bool {
+    match &event.token {
+        Some(token) if !expected_token.is_empty() => {
+            constant_time_eq(token.as_bytes(), expected_token.as_bytes())
+        }
+        _ => false,
+    }
+}
+
+/// Check a raw notification payload and the token received with it against the channel token.
+///
+/// `token` is the token received with the notification, and `expected_token` the one set on the
+/// channel. When `payload` is a JSON object with a `token` field, that field must match as well,
+/// so a forwarded payload cannot be replayed with a different token.
+///
+/// The tokens are compared in constant time, to avoid leaking the expected token through
+/// timing. An empty `expected_token` never matches.
+pub fn verify_webhook_signature(payload: &[u8], token: &str, expected_token: &str) -> bool {
+    if expected_token.is_empty() || !constant_time_eq(token.as_bytes(), expected_token.as_bytes()) {
+        return false;
+    }
+
+    match serde_json::from_slice::<serde_json::Value>(payload) {
+        Ok(serde_json::Value::Object(map)) => match map.get("token") {
+            Some(serde_json::Value::String(payload_token)) => {
+                constant_time_eq(payload_token.as_bytes(), expected_token.as_bytes())
+            }
+            Some(_) => false,
+            None => true,
+        },
+        _ => true,
+    }
+}
+
+/// Compare two byte strings in a time that only depends on their length.
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+
+    left.iter()
+        .zip(right)
+        .fold(0u8, |difference, (l, r)| difference | (l ^ r))
+        == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// Tests that the `token` field of a JSON payload must match the expected token too.
+    fn payload_token_must_match() {
+        let payload = br#"{"channel_id":"1000000068001","token":"other-secret"}"#;
+
+        assert!(!verify_webhook_signature(
+            payload,
+            "shared-secret",
+            "shared-secret"
+        ));
+    }
+
+    #[test]
+    /// Tests that a payload that is not JSON is checked on the received token alone.
+    fn non_json_payload_uses_token() {
+        assert!(verify_webhook_signature(
+            b"Lead created",
+            "shared-secret",
+            "shared-secret"
+        ));
+        assert!(!verify_webhook_signature(b"Lead created", "", ""));
+    }
+
+    #[test]
+    /// Tests that an `insert` notification deserializes and carries the channel token.
+    fn notification_event_insert() {
+        let payload = r#"{"server_time":1683018000000,"query_params":{},"module":"Contacts","resource_uri":"https://www.zohoapis.com/crm/v2/Contacts","ids":["554023000000527001"],"affected_fields":[],"operation":"insert","channel_id":"1000000068001","token":"shared-secret"}"#;
+
+        let event: NotificationEvent = serde_json::from_str(payload).unwrap();
+
+        assert_eq!(event.channel_id, 1000000068001);
+        assert_eq!(event.module_api_name, "Contacts");
+        assert_eq!(event.operation, "insert");
+        assert_eq!(event.ids, vec!["554023000000527001"]);
+        assert_eq!(event.server_time, Some(1683018000000));
+        assert!(verify_token(&event, "shared-secret"));
+        assert!(!verify_token(&event, "other-secret"));
+    }
+
+    #[test]
+    /// Tests that an `update` notification keeps the fields changed on each record.
+    fn notification_event_update() {
+        let payload = r#"{"server_time":1683018000000,"query_params":{},"module":"Contacts","resource_uri":"https://www.zohoapis.com/crm/v2/Contacts","ids":["554023000000527001","554023000000527002"],"affected_fields":[{"554023000000527001":["Last_Name","Email"]},{"554023000000527002":["Phone"]}],"operation":"update","channel_id":1000000068001,"token":"shared-secret"}"#;
+
+        let event: NotificationEvent = serde_json::from_str(payload).unwrap();
+
+        assert_eq!(event.operation, "update");
+        assert_eq!(event.ids.len(), 2);
+        assert_eq!(
+            event.affected_fields[0]["554023000000527001"],
+            serde_json::json!(["Last_Name", "Email"])
+        );
+    }
+
+    #[test]
+    /// Tests that a `delete` notification without a token never verifies.
+    fn notification_event_delete() {
+        let payload = r#"{"server_time":1683018000000,"module_api_name":"Deals","ids":["554023000000527003"],"operation":"delete","channel_id":"1000000068001"}"#;
+
+        let event: NotificationEvent = serde_json::from_str(payload).unwrap();
+
+        assert_eq!(event.module_api_name, "Deals");
+        assert_eq!(event.operation, "delete");
+        assert!(event.affected_fields.is_empty());
+        assert!(!verify_token(&event, "shared-secret"));
+        assert!(!verify_token(&event, ""));
+    }
+
+    #[test]
+    /// Tests that `constant_time_eq()` matches only byte strings of the same content and length.
+    fn constant_time_eq_compares_bytes() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secrets"));
+    }
+}